@@ -0,0 +1,155 @@
+//! Allocation counts of each phase, from a global allocator that counts every allocation. It is
+//! only installed with the `alloc-stats` feature, since the counting slows down every allocation.
+
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    fmt::{self, Display, Formatter},
+    sync::atomic::{AtomicU64, AtomicUsize, Ordering},
+};
+
+static ALLOCATIONS: AtomicU64 = AtomicU64::new(0);
+static ALLOCATED: AtomicU64 = AtomicU64::new(0);
+static LIVE: AtomicUsize = AtomicUsize::new(0);
+static PEAK: AtomicUsize = AtomicUsize::new(0);
+
+#[cfg(feature = "alloc-stats")]
+#[global_allocator]
+static ALLOCATOR: Counting = Counting;
+
+/// The [`System`] allocator, counting the allocations and the live bytes.
+#[derive(Debug, Default, Copy, Clone)]
+pub struct Counting;
+
+fn allocated(size: usize) {
+    ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+    ALLOCATED.fetch_add(size as u64, Ordering::Relaxed);
+    let live = LIVE.fetch_add(size, Ordering::Relaxed) + size;
+    PEAK.fetch_max(live, Ordering::Relaxed);
+}
+
+// SAFETY: every call is passed on to `System` unchanged.
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = unsafe { System.alloc(layout) };
+        if !ptr.is_null() {
+            allocated(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = unsafe { System.alloc_zeroed(layout) };
+        if !ptr.is_null() {
+            allocated(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) };
+        LIVE.fetch_sub(layout.size(), Ordering::Relaxed);
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new = unsafe { System.realloc(ptr, layout, new_size) };
+        if !new.is_null() {
+            LIVE.fetch_sub(layout.size(), Ordering::Relaxed);
+            allocated(new_size);
+        }
+        new
+    }
+}
+
+/// What a phase allocated. Reallocations count as allocations of their new size.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq)]
+pub struct AllocStats {
+    pub allocations: u64,
+    pub bytes: u64,
+    /// The most bytes live at once, beyond those live when the phase started.
+    pub peak: usize,
+}
+
+impl Display for AllocStats {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} allocs  {} allocated  {} peak",
+            self.allocations,
+            Bytes(self.bytes),
+            Bytes(self.peak as u64)
+        )
+    }
+}
+
+/// Runs `f` and counts what it allocates, or `None` without the `alloc-stats` feature. Allocations
+/// on other threads in the meantime are counted as well, and the peak of a concurrent measurement
+/// is off.
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Option<AllocStats>) {
+    if !cfg!(feature = "alloc-stats") {
+        return (f(), None);
+    }
+    let allocations = ALLOCATIONS.load(Ordering::Relaxed);
+    let bytes = ALLOCATED.load(Ordering::Relaxed);
+    let live = LIVE.load(Ordering::Relaxed);
+    PEAK.store(live, Ordering::Relaxed);
+    let value = f();
+    let stats = AllocStats {
+        allocations: ALLOCATIONS.load(Ordering::Relaxed) - allocations,
+        bytes: ALLOCATED.load(Ordering::Relaxed) - bytes,
+        peak: PEAK.load(Ordering::Relaxed).saturating_sub(live),
+    };
+    (value, Some(stats))
+}
+
+/// Displays a byte count with three significant digits in a fitting binary unit.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct Bytes(pub u64);
+
+impl Display for Bytes {
+    #[allow(clippy::cast_precision_loss)]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+        if self.0 < 1024 {
+            return f.pad(&format!("{}B", self.0));
+        }
+        let mut value = self.0 as f64 / 1024.0;
+        let mut unit = 0;
+        while value >= 1024.0 && unit < UNITS.len() - 1 {
+            value /= 1024.0;
+            unit += 1;
+        }
+        let decimals = match value {
+            v if v >= 100.0 => 0,
+            v if v >= 10.0 => 1,
+            _ => 2,
+        };
+        f.pad(&format!("{value:.decimals$}{}", UNITS[unit]))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{measure, Bytes};
+
+    #[test]
+    fn counts_allocations() {
+        let (bytes, stats) = measure(|| vec![0_u8; 4096]);
+        assert_eq!(bytes.len(), 4096);
+        if cfg!(feature = "alloc-stats") {
+            // Other tests allocate concurrently, so these are lower bounds, and the peak is off as
+            // they measure too.
+            let stats = stats.unwrap();
+            assert!(stats.allocations >= 1);
+            assert!(stats.bytes >= 4096);
+        } else {
+            assert_eq!(stats, None);
+        }
+    }
+
+    #[test]
+    fn displays_bytes() {
+        assert_eq!(Bytes(870).to_string(), "870B");
+        assert_eq!(Bytes(1264).to_string(), "1.23KiB");
+        assert_eq!(Bytes(45 << 20).to_string(), "45.0MiB");
+    }
+}
@@ -0,0 +1,230 @@
+//! The answers known to be correct for our inputs, kept in `answers.toml` to catch regressions.
+
+use crate::{day_key, read_toml, write_toml, Answer, AocError, Part};
+use serde::{Deserialize, Deserializer, Serialize};
+use std::{
+    collections::BTreeMap,
+    env,
+    path::{Path, PathBuf},
+};
+
+/// Environment variable overriding [`DEFAULT_ANSWERS_FILE`].
+pub const ANSWERS_ENV: &str = "AOC_ANSWERS";
+/// Known answers file used when [`ANSWERS_ENV`] is not set.
+pub const DEFAULT_ANSWERS_FILE: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/answers.toml");
+
+const HEADER: &str =
+    "# Known answers, recorded by `aoc <days> --accept` and checked by `aoc verify`.\n\n";
+
+/// The known answers to one day, and the SHA-256 of the input they are for if it was recorded.
+/// Answers are written as strings, but hand-written integers are read as well.
+#[derive(Debug, Default, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct DayAnswers {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub input_sha256: Option<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "known_answer"
+    )]
+    pub part_one: Option<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "known_answer"
+    )]
+    pub part_two: Option<String>,
+}
+
+fn known_answer<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<String>, D::Error> {
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Known {
+        Text(String),
+        Number(i64),
+    }
+    Ok(
+        Option::<Known>::deserialize(deserializer)?.map(|known| match known {
+            Known::Text(text) => text,
+            Known::Number(n) => n.to_string(),
+        }),
+    )
+}
+
+impl DayAnswers {
+    #[must_use]
+    pub fn get(&self, part: Part) -> Option<&str> {
+        match part {
+            Part::One => self.part_one.as_deref(),
+            Part::Two => self.part_two.as_deref(),
+        }
+    }
+
+    fn set(&mut self, part: Part, answer: String) {
+        match part {
+            Part::One => self.part_one = Some(answer),
+            Part::Two => self.part_two = Some(answer),
+        }
+    }
+}
+
+/// How an answer compares to the known one, see [`Answers::check`].
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum Check {
+    /// No answer is known for the part.
+    Unknown,
+    /// The known answer is for another input.
+    OtherInput,
+    Correct,
+    Wrong {
+        known: String,
+    },
+}
+
+/// The known answers of every day, stored as a `[dayNN]` table per day.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Answers {
+    path: PathBuf,
+    days: BTreeMap<String, DayAnswers>,
+}
+
+impl Answers {
+    /// Loads the known answers from `path`, which need not exist yet.
+    ///
+    /// # Errors
+    ///
+    /// If the file cannot be read or is not valid.
+    pub fn load(path: impl Into<PathBuf>) -> Result<Self, AocError> {
+        let path = path.into();
+        let days = read_toml(&path)?;
+        Ok(Self { path, days })
+    }
+
+    /// Loads the known answers from [`ANSWERS_ENV`] or [`DEFAULT_ANSWERS_FILE`].
+    ///
+    /// # Errors
+    ///
+    /// See [`Answers::load`].
+    pub fn default_location() -> Result<Self, AocError> {
+        Self::load(
+            env::var_os(ANSWERS_ENV).map_or_else(|| DEFAULT_ANSWERS_FILE.into(), PathBuf::from),
+        )
+    }
+
+    #[must_use]
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    #[must_use]
+    pub fn day(&self, day: u8) -> Option<&DayAnswers> {
+        self.days.get(&day_key(day))
+    }
+
+    /// Compares `answer` to the known answer of `day` and `part`, if that is for the input with
+    /// the [`input_hash`](crate::input_hash) `input_sha256`, or for an unrecorded input.
+    #[must_use]
+    pub fn check(&self, day: u8, part: Part, input_sha256: &str, answer: &Answer) -> Check {
+        let Some(known) = self.day(day) else {
+            return Check::Unknown;
+        };
+        if known
+            .input_sha256
+            .as_deref()
+            .is_some_and(|known| known != input_sha256)
+        {
+            return Check::OtherInput;
+        }
+        match known.get(part) {
+            None => Check::Unknown,
+            Some(known) if *known == answer.to_string() => Check::Correct,
+            Some(known) => Check::Wrong {
+                known: known.to_string(),
+            },
+        }
+    }
+
+    /// Records `answer` as the known answer of `day` and `part` for the input with the
+    /// [`input_hash`](crate::input_hash) `input_sha256`, forgetting answers for other inputs.
+    pub fn accept(&mut self, day: u8, part: Part, input_sha256: &str, answer: &Answer) {
+        let known = self.days.entry(day_key(day)).or_default();
+        if known.input_sha256.as_deref() != Some(input_sha256) {
+            *known = DayAnswers {
+                input_sha256: Some(input_sha256.to_string()),
+                ..DayAnswers::default()
+            };
+        }
+        known.set(part, answer.to_string());
+    }
+
+    /// Writes the known answers back to their file.
+    ///
+    /// # Errors
+    ///
+    /// If the file cannot be written.
+    pub fn save(&self) -> Result<(), AocError> {
+        write_toml(&self.path, HEADER, &self.days)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Answers, Check};
+    use crate::{AocError, Part};
+    use std::fs;
+
+    #[test]
+    fn accepts_checks_and_saves_answers() {
+        let dir = std::env::temp_dir().join(format!("aoc-answers-test-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("answers.toml");
+        let _ = fs::remove_file(&path);
+
+        let mut answers = Answers::load(&path).unwrap();
+        assert_eq!(
+            answers.check(7, Part::One, "aa", &6440.into()),
+            Check::Unknown
+        );
+        answers.accept(7, Part::One, "aa", &6440.into());
+        answers.accept(7, Part::Two, "aa", &5905.into());
+        answers.save().unwrap();
+
+        let mut answers = Answers::load(&path).unwrap();
+        assert_eq!(
+            answers.check(7, Part::One, "aa", &6440.into()),
+            Check::Correct
+        );
+        assert_eq!(
+            answers.check(7, Part::Two, "aa", &5904.into()),
+            Check::Wrong {
+                known: "5905".to_string()
+            }
+        );
+        assert_eq!(
+            answers.check(7, Part::Two, "bb", &5905.into()),
+            Check::OtherInput
+        );
+        assert_eq!(answers.check(8, Part::One, "aa", &6.into()), Check::Unknown);
+
+        answers.accept(7, Part::One, "bb", &6592.into());
+        assert_eq!(answers.day(7).unwrap().part_two, None);
+
+        fs::write(&path, "[day07]\npart_one = 6440\npart_two = \"5905\"\n").unwrap();
+        let answers = Answers::load(&path).unwrap();
+        assert_eq!(
+            answers.check(7, Part::One, "aa", &6440.into()),
+            Check::Correct
+        );
+        assert_eq!(
+            answers.check(7, Part::Two, "aa", &5905.into()),
+            Check::Correct
+        );
+
+        fs::write(&path, "[day07]\npart_one = 64.4\n").unwrap();
+        assert!(matches!(
+            Answers::load(&path),
+            Err(AocError::InvalidToml { .. })
+        ));
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
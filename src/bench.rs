@@ -0,0 +1,227 @@
+//! Timings of each day's parse and parts, and a saved baseline to compare them against.
+
+use crate::{
+    alloc_stats::{self, AllocStats},
+    day_key, read_toml, write_toml, AocError, Part,
+};
+use std::{
+    collections::BTreeMap,
+    env,
+    fmt::{self, Display, Formatter},
+    path::{Path, PathBuf},
+    time::{Duration, Instant},
+};
+
+/// Environment variable overriding [`DEFAULT_BASELINE_FILE`].
+pub const BASELINE_ENV: &str = "AOC_BENCH_BASELINE";
+/// Baseline file used when [`BASELINE_ENV`] is not set. Timings only compare on the same machine,
+/// so it is not checked in.
+pub const DEFAULT_BASELINE_FILE: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/bench.toml");
+/// A phase is not repeated once its runs have taken this long, so that slow parts like day 5 part
+/// two finish in reasonable time.
+pub const BUDGET: Duration = Duration::from_secs(5);
+
+const HEADER: &str = "# Median nanoseconds per phase, saved by `aoc bench <days> --save`.\n\n";
+
+/// What is timed: parsing the input or solving one of the parts.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum Phase {
+    Parse,
+    Part(Part),
+}
+
+impl Phase {
+    fn key(self) -> &'static str {
+        match self {
+            Phase::Parse => "parse",
+            Phase::Part(Part::One) => "part_one",
+            Phase::Part(Part::Two) => "part_two",
+        }
+    }
+}
+
+impl Display for Phase {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Phase::Parse => f.write_str("parse"),
+            Phase::Part(part) => write!(f, "{part}"),
+        }
+    }
+}
+
+/// The spread of the runs of a phase.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct Stats {
+    pub runs: usize,
+    pub median: Duration,
+    pub min: Duration,
+    pub max: Duration,
+    /// What the first run allocated, with the `alloc-stats` feature.
+    pub alloc: Option<AllocStats>,
+}
+
+/// Runs `f` `runs` times, or fewer if they exceed [`BUDGET`], but at least once.
+pub(crate) fn measure(runs: u32, mut f: impl FnMut()) -> Stats {
+    let start = Instant::now();
+    let mut samples = Vec::new();
+    let mut alloc = None;
+    while samples.is_empty() || (samples.len() < runs as usize && start.elapsed() < BUDGET) {
+        let run = Instant::now();
+        if samples.is_empty() {
+            alloc = alloc_stats::measure(&mut f).1;
+        } else {
+            f();
+        }
+        samples.push(run.elapsed());
+    }
+    samples.sort_unstable();
+    Stats {
+        runs: samples.len(),
+        median: samples[samples.len() / 2],
+        min: samples[0],
+        max: samples[samples.len() - 1],
+        alloc,
+    }
+}
+
+/// Displays a duration with three significant digits in a fitting unit.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct Elapsed(pub Duration);
+
+impl Display for Elapsed {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let secs = self.0.as_secs_f64();
+        let (value, unit) = if secs >= 1.0 {
+            (secs, "s")
+        } else if secs >= 1e-3 {
+            (secs * 1e3, "ms")
+        } else if secs >= 1e-6 {
+            (secs * 1e6, "µs")
+        } else {
+            (secs * 1e9, "ns")
+        };
+        let decimals = match value {
+            v if v >= 100.0 => 0,
+            v if v >= 10.0 => 1,
+            _ => 2,
+        };
+        let text = format!("{value:.decimals$}{unit}");
+        f.pad(&text)
+    }
+}
+
+/// Saved median timings of every day, stored as a `[dayNN]` table per day.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Baseline {
+    path: PathBuf,
+    days: BTreeMap<String, BTreeMap<String, u64>>,
+}
+
+impl Baseline {
+    /// Loads the baseline from `path`, which need not exist yet.
+    ///
+    /// # Errors
+    ///
+    /// If the file cannot be read or is not valid.
+    pub fn load(path: impl Into<PathBuf>) -> Result<Self, AocError> {
+        let path = path.into();
+        let days = read_toml(&path)?;
+        Ok(Self { path, days })
+    }
+
+    /// Loads the baseline from [`BASELINE_ENV`] or [`DEFAULT_BASELINE_FILE`].
+    ///
+    /// # Errors
+    ///
+    /// See [`Baseline::load`].
+    pub fn default_location() -> Result<Self, AocError> {
+        Self::load(
+            env::var_os(BASELINE_ENV).map_or_else(|| DEFAULT_BASELINE_FILE.into(), PathBuf::from),
+        )
+    }
+
+    #[must_use]
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// The saved median of `phase` of `day`.
+    #[must_use]
+    pub fn median(&self, day: u8, phase: Phase) -> Option<Duration> {
+        self.days
+            .get(&day_key(day))?
+            .get(phase.key())
+            .copied()
+            .map(Duration::from_nanos)
+    }
+
+    /// How much slower `stats` is than the saved median, in percent; negative if faster.
+    #[must_use]
+    pub fn change(&self, day: u8, phase: Phase, stats: &Stats) -> Option<f64> {
+        let saved = self.median(day, phase)?.as_secs_f64();
+        (saved > 0.0).then(|| (stats.median.as_secs_f64() / saved - 1.0) * 100.0)
+    }
+
+    /// Saves the median of `stats` as the baseline of `phase` of `day`.
+    pub fn record(&mut self, day: u8, phase: Phase, stats: &Stats) {
+        self.days.entry(day_key(day)).or_default().insert(
+            phase.key().to_string(),
+            u64::try_from(stats.median.as_nanos()).unwrap_or(u64::MAX),
+        );
+    }
+
+    /// Writes the baseline back to its file.
+    ///
+    /// # Errors
+    ///
+    /// If the file cannot be written.
+    pub fn save(&self) -> Result<(), AocError> {
+        write_toml(&self.path, HEADER, &self.days)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{measure, Baseline, Elapsed, Phase};
+    use crate::Part;
+    use std::{fs, time::Duration};
+
+    #[test]
+    fn measures_runs() {
+        let mut calls = 0;
+        let stats = measure(5, || calls += 1);
+        assert_eq!((calls, stats.runs), (5, 5));
+        assert!(stats.min <= stats.median && stats.median <= stats.max);
+        assert_eq!(measure(0, || {}).runs, 1);
+    }
+
+    #[test]
+    fn displays_elapsed() {
+        assert_eq!(Elapsed(Duration::from_nanos(870)).to_string(), "870ns");
+        assert_eq!(Elapsed(Duration::from_micros(1234)).to_string(), "1.23ms");
+        assert_eq!(Elapsed(Duration::from_millis(45_600)).to_string(), "45.6s");
+    }
+
+    #[test]
+    fn compares_to_saved_baseline() {
+        let dir = std::env::temp_dir().join(format!("aoc-bench-test-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("bench.toml");
+        let _ = fs::remove_file(&path);
+
+        let mut stats = measure(1, || {});
+        stats.median = Duration::from_millis(4);
+        let phase = Phase::Part(Part::Two);
+        let mut baseline = Baseline::load(&path).unwrap();
+        assert_eq!(baseline.change(5, phase, &stats), None);
+        baseline.record(5, phase, &stats);
+        baseline.save().unwrap();
+
+        let baseline = Baseline::load(&path).unwrap();
+        assert_eq!(baseline.median(5, phase), Some(Duration::from_millis(4)));
+        assert_eq!(baseline.median(5, Phase::Parse), None);
+        stats.median = Duration::from_millis(5);
+        assert_eq!(baseline.change(5, phase, &stats), Some(25.0));
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
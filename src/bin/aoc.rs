@@ -0,0 +1,1153 @@
+use aoc_2023::{
+    alloc_stats::AllocStats,
+    answers::{Answers, Check},
+    bench::{Baseline, Elapsed},
+    examples::{self, DEFAULT_EXAMPLES_DIR},
+    input_hash, scaffold,
+    submit::{self, Outcome, Submissions},
+    Answer, AocError, Args, Client, Entry, Failure, InputArg, InputDir, Part, Puzzle, DAYS, YEAR,
+};
+use notify::{event::ModifyKind, EventKind, RecursiveMode, Watcher};
+use serde::Serialize;
+use std::{
+    env,
+    fmt::{self, Display, Formatter, Write},
+    fs, iter,
+    path::{Path, PathBuf},
+    process::{Command, ExitCode},
+    str::FromStr,
+    sync::mpsc,
+    thread::{self, ScopedJoinHandle},
+    time::Duration,
+};
+
+const USAGE: &str =
+    "usage: aoc <days> [--input <file> | --stdin] [--part 1|2] [--timeout <seconds>] [--variants]
+         [--accept] [--format <f>]
+       aoc verify [<days>] [--input <file> | --stdin] [--part 1|2] [--timeout <seconds>]
+         [--variants] [--format <f>]
+       aoc bench [<days>] [--input <file> | --stdin] [--part 1|2] [--runs <n>] [--save]
+       aoc examples [<days>]
+       aoc watch <day> [--input <file>] [--part 1|2] [--timeout <seconds>] [--variants]
+       aoc readme [--redact]
+       aoc new-day <day> <title>
+       aoc submit <day> 1|2 [--input <file> | --stdin] [--timeout <seconds>]
+  <days> is a day, e.g. `7`, an inclusive range, e.g. `3..=9`, or `all`, the default of verify,
+    bench and examples
+  --timeout gives up on the parse or a part after that many seconds, though it keeps running in
+    the background and may slow down the parts after it
+  --variants also runs the days' alternative solvers and fails if they disagree with the main
+    ones
+  --accept records the answers as known answers, which verify compares against
+  --format prints the answers as a table, the default, as JSON lines or as CSV with the fields
+    day, part, answer, time_ns, input_sha256, status, error and variant, followed by allocations,
+    allocated_bytes and peak_bytes when built with --features alloc-stats
+  --runs sets how often bench times each phase, 10 by default
+  --save saves the timings as the baseline that later benchmarks are compared to
+  bench and solving also count the allocations, bytes allocated and peak live bytes of each phase
+    or part when built with --features alloc-stats, which solves the days one at a time
+  examples checks the days' solvers, variants included, against the examples in examples/NN/
+  watch rebuilds and reruns the examples and input of the day whenever a source, one of its
+    examples or its input changes
+  readme solves every day and rewrites the results table in README.md, --redact leaves out the
+    answers
+  new-day creates a day's module from 00.rs.template, its binary and an example to fill in
+  submit solves a part and posts the answer to adventofcode.com, unless an earlier submission
+    tells it is wrong, and records the outcome in submissions.toml and a correct answer as known
+  Build with --release for meaningful timings.";
+
+const DEFAULT_RUNS: u32 = 10;
+
+fn day(s: &str) -> Result<u8, AocError> {
+    s.parse()
+        .ok()
+        .and_then(|day| Puzzle::new(YEAR, day).ok())
+        .map(Puzzle::day)
+        .ok_or_else(|| AocError::InvalidDayNumber(s.to_string()))
+}
+
+/// The registered days picked by `selector`.
+fn select(selector: &str) -> Result<Vec<Entry>, AocError> {
+    let days = if selector == "all" {
+        1..=25
+    } else if let Some((first, last)) = selector.split_once("..=") {
+        day(first)?..=day(last)?
+    } else {
+        let day = day(selector)?;
+        day..=day
+    };
+    let entries: Vec<_> = DAYS
+        .iter()
+        .filter(|entry| days.contains(&entry.day))
+        .copied()
+        .collect();
+    if entries.is_empty() {
+        return Err(AocError::InvalidArgument(format!(
+            "no implemented day matches `{selector}`"
+        )));
+    }
+    Ok(entries)
+}
+
+#[derive(Copy, Clone, Eq, PartialEq)]
+enum Mode {
+    Solve,
+    Accept,
+    Verify,
+}
+
+/// Removes `flag` from `args`, returning whether it was there.
+fn take_flag(args: &mut Vec<String>, flag: &str) -> bool {
+    let len = args.len();
+    args.retain(|arg| arg != flag);
+    args.len() != len
+}
+
+/// Removes `flag` and its value from `args`, returning the value.
+fn take_value(args: &mut Vec<String>, flag: &str) -> Result<Option<String>, AocError> {
+    let Some(i) = args.iter().position(|arg| arg == flag) else {
+        return Ok(None);
+    };
+    if i + 1 == args.len() {
+        return Err(AocError::InvalidArgument(format!("{flag} needs a value")));
+    }
+    let value = args.remove(i + 1);
+    args.remove(i);
+    Ok(Some(value))
+}
+
+fn run(args: Vec<String>) -> Result<bool, AocError> {
+    let mut args = args.into_iter().peekable();
+    let command = args.next_if(|arg| {
+        [
+            "verify", "bench", "examples", "watch", "readme", "new-day", "submit",
+        ]
+        .contains(&arg.as_str())
+    });
+    if command.as_deref() == Some("new-day") {
+        let (Some(day), Some(title), None) = (args.next(), args.next(), args.next()) else {
+            return Err(AocError::InvalidArgument(
+                "new-day takes a day and the puzzle title".to_string(),
+            ));
+        };
+        let paths = scaffold::new_day(
+            Path::new(env!("CARGO_MANIFEST_DIR")),
+            self::day(&day)?,
+            &title,
+        )?;
+        for path in paths {
+            println!("wrote {}", path.display());
+        }
+        return Ok(true);
+    }
+    if command.as_deref() == Some("submit") {
+        return submit(args);
+    }
+    if command.as_deref() == Some("readme") {
+        let mut args: Vec<_> = args.collect();
+        let redact = take_flag(&mut args, "--redact");
+        if !args.is_empty() {
+            return Err(AocError::InvalidArgument(
+                "readme solves every day from its usual input and only takes --redact".to_string(),
+            ));
+        }
+        return readme(&Args::default(), redact);
+    }
+    let selector = match args.next_if(|arg| !arg.starts_with("--")) {
+        Some(selector) => selector,
+        None if command.is_some() => "all".to_string(),
+        None => return Err(AocError::InvalidArgument("missing <days>".to_string())),
+    };
+    let entries = select(&selector)?;
+    let mut args: Vec<_> = args.collect();
+    match command.as_deref() {
+        Some("examples") if args.is_empty() => return examples(&entries),
+        Some("examples") => {
+            return Err(AocError::InvalidArgument(
+                "examples takes no options".to_string(),
+            ))
+        }
+        Some("watch") => {
+            let parsed = Args::parse(args.clone())?;
+            if entries.len() > 1 || parsed.input == Some(InputArg::Stdin) {
+                return Err(AocError::InvalidArgument(
+                    "watch needs a single day and cannot read stdin".to_string(),
+                ));
+            }
+            return watch(&entries[0], parsed.input.as_ref(), &args);
+        }
+        _ => {}
+    }
+    let accept = take_flag(&mut args, "--accept");
+    let save = take_flag(&mut args, "--save");
+    let format = take_value(&mut args, "--format")?
+        .map(|format| format.parse())
+        .transpose()?;
+    let runs = take_value(&mut args, "--runs")?
+        .map(|runs| {
+            runs.parse()
+                .map_err(|_| AocError::InvalidArgument(format!("`{runs}` is not a number of runs")))
+        })
+        .transpose()?;
+    let args = Args::parse(args)?;
+    if entries.len() > 1 && args.input.is_some() {
+        return Err(AocError::InvalidArgument(
+            "--input and --stdin need a single day".to_string(),
+        ));
+    }
+    let bench_args = save || runs.is_some();
+    let format = format.unwrap_or(Format::Table);
+    match (command.as_deref(), accept, bench_args) {
+        (None, false, false) => solve(&entries, &args, Mode::Solve, format),
+        (None, true, false) => solve(&entries, &args, Mode::Accept, format),
+        (Some("verify"), false, false) => solve(&entries, &args, Mode::Verify, format),
+        (Some("bench"), false, _)
+            if format == Format::Table && args.timeout.is_none() && !args.variants =>
+        {
+            bench(&entries, &args, runs.unwrap_or(DEFAULT_RUNS), save)
+        }
+        _ => Err(AocError::InvalidArgument(
+            "--accept only applies to solving, --runs and --save only to bench, which has no \
+             --format, --timeout or --variants"
+                .to_string(),
+        )),
+    }
+}
+
+#[derive(Copy, Clone, Eq, PartialEq)]
+enum Format {
+    Table,
+    Json,
+    Csv,
+}
+
+impl FromStr for Format {
+    type Err = AocError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "table" => Ok(Format::Table),
+            "json" => Ok(Format::Json),
+            "csv" => Ok(Format::Csv),
+            _ => Err(AocError::InvalidArgument(format!(
+                "`{s}` is not a format, expected table, json or csv"
+            ))),
+        }
+    }
+}
+
+/// How a part fared, the last column of the table.
+enum Status {
+    Checked(Check),
+    /// A variant's answer differs from the `main` solver's.
+    Disagrees {
+        main: String,
+    },
+    Failed(Failure),
+    NoInput(AocError),
+}
+
+impl Status {
+    /// The `status` of a [`Record`].
+    fn kind(&self) -> &'static str {
+        match self {
+            Status::Checked(Check::Correct) => "ok",
+            Status::Checked(Check::Wrong { .. }) => "wrong",
+            Status::Checked(Check::Unknown | Check::OtherInput) => "unchecked",
+            Status::Disagrees { .. } => "disagrees",
+            Status::Failed(Failure::Unimplemented) => "unimplemented",
+            Status::Failed(Failure::Panicked { .. }) => "panicked",
+            Status::Failed(Failure::TimedOut(_)) => "timed_out",
+            Status::NoInput(_) => "no_input",
+        }
+    }
+
+    /// The `error` of a [`Record`].
+    fn error(&self) -> Option<String> {
+        match self {
+            Status::Failed(Failure::Panicked { message, location }) => Some(match location {
+                Some(location) => format!("{location}: {message}"),
+                None => message.clone(),
+            }),
+            Status::Disagrees { main } => Some(format!("the main solver's answer is {main}")),
+            Status::Failed(failure @ Failure::TimedOut(_)) => Some(failure.to_string()),
+            Status::NoInput(e) => Some(e.to_string()),
+            Status::Checked(_) | Status::Failed(Failure::Unimplemented) => None,
+        }
+    }
+
+    fn failed(&self, mode: Mode) -> bool {
+        match self {
+            Status::Checked(Check::Wrong { .. }) => mode == Mode::Verify,
+            Status::Checked(_) | Status::Failed(Failure::Unimplemented) => false,
+            Status::Disagrees { .. } | Status::Failed(_) | Status::NoInput(_) => true,
+        }
+    }
+}
+
+impl Display for Status {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Status::Checked(Check::Correct) => f.write_str("ok"),
+            Status::Checked(Check::Wrong { known }) => {
+                write!(f, "wrong, the known answer is {known}")
+            }
+            Status::Checked(Check::Unknown) => f.write_str("no known answer"),
+            Status::Checked(Check::OtherInput) => f.write_str("known answer is for another input"),
+            Status::Disagrees { main } => {
+                write!(f, "disagrees, the main solver's answer is {main}")
+            }
+            Status::Failed(failure) => write!(f, "{failure}"),
+            Status::NoInput(e) => write!(f, "no input: {e}"),
+        }
+    }
+}
+
+struct Row {
+    day: u8,
+    part: Option<Part>,
+    variant: Option<&'static str>,
+    answer: Option<Answer>,
+    elapsed: Option<Duration>,
+    /// What the part allocated, with the `alloc-stats` feature.
+    alloc: Option<AllocStats>,
+    input_sha256: Option<String>,
+    status: Status,
+}
+
+impl Row {
+    fn failed(day: u8, part: Option<Part>, input_sha256: Option<String>, status: Status) -> Self {
+        Self {
+            day,
+            part,
+            variant: None,
+            answer: None,
+            elapsed: None,
+            alloc: None,
+            input_sha256,
+            status,
+        }
+    }
+
+    /// Whether the answer was compared to a known one.
+    fn checked(&self) -> bool {
+        matches!(
+            self.status,
+            Status::Checked(Check::Correct | Check::Wrong { .. })
+        )
+    }
+
+    fn record(&self) -> Record<'_> {
+        Record {
+            day: self.day,
+            part: self.part.map(Part::number),
+            answer: self.answer.as_ref().map(ToString::to_string),
+            time_ns: self
+                .elapsed
+                .map(|elapsed| u64::try_from(elapsed.as_nanos()).unwrap_or(u64::MAX)),
+            input_sha256: self.input_sha256.as_deref(),
+            status: self.status.kind(),
+            error: self.status.error(),
+            variant: self.variant,
+            allocations: self.alloc.map(|alloc| alloc.allocations),
+            allocated_bytes: self.alloc.map(|alloc| alloc.bytes),
+            peak_bytes: self.alloc.map(|alloc| alloc.peak),
+        }
+    }
+}
+
+/// A [`Row`] as printed by `--format json` or `csv`. Scripts rely on the fields, so only ever add
+/// new ones at the end.
+#[derive(Serialize)]
+struct Record<'a> {
+    day: u8,
+    part: Option<u8>,
+    answer: Option<String>,
+    time_ns: Option<u64>,
+    input_sha256: Option<&'a str>,
+    status: &'static str,
+    error: Option<String>,
+    variant: Option<&'static str>,
+    #[serde(skip_serializing_if = "without_alloc_stats")]
+    allocations: Option<u64>,
+    #[serde(skip_serializing_if = "without_alloc_stats")]
+    allocated_bytes: Option<u64>,
+    #[serde(skip_serializing_if = "without_alloc_stats")]
+    peak_bytes: Option<usize>,
+}
+
+/// Whether the allocation fields are left out, as they are without the `alloc-stats` feature.
+#[allow(clippy::ref_option)]
+fn without_alloc_stats<T>(_: &Option<T>) -> bool {
+    !cfg!(feature = "alloc-stats")
+}
+
+const CSV_HEADER: &str = if cfg!(feature = "alloc-stats") {
+    "day,part,answer,time_ns,input_sha256,status,error,variant,allocations,allocated_bytes,\
+     peak_bytes"
+} else {
+    "day,part,answer,time_ns,input_sha256,status,error,variant"
+};
+
+impl Record<'_> {
+    fn csv(&self) -> String {
+        fn field(value: Option<impl ToString>) -> String {
+            let value = value.map(|value| value.to_string()).unwrap_or_default();
+            if value.contains([',', '"', '\n', '\r']) {
+                format!("\"{}\"", value.replace('"', "\"\""))
+            } else {
+                value
+            }
+        }
+        let mut fields = vec![
+            field(Some(self.day)),
+            field(self.part),
+            field(self.answer.as_ref()),
+            field(self.time_ns),
+            field(self.input_sha256),
+            field(Some(self.status)),
+            field(self.error.as_ref()),
+            field(self.variant),
+        ];
+        if !without_alloc_stats(&self.allocations) {
+            fields.extend([
+                field(self.allocations),
+                field(self.allocated_bytes),
+                field(self.peak_bytes),
+            ]);
+        }
+        fields.join(",")
+    }
+}
+
+fn print_rows(rows: &[Row], format: Format) {
+    match format {
+        Format::Table => print_table(rows),
+        Format::Json => {
+            for row in rows {
+                let json = serde_json::to_string(&row.record()).expect("records serialize to JSON");
+                println!("{json}");
+            }
+        }
+        Format::Csv => {
+            println!("{CSV_HEADER}");
+            for row in rows {
+                println!("{}", row.record().csv());
+            }
+        }
+    }
+}
+
+fn print_table(rows: &[Row]) {
+    let header = ["day", "part", "answer", "time", "allocations", "status"].map(String::from);
+    let cells: Vec<_> = rows
+        .iter()
+        .map(|row| {
+            [
+                row.day.to_string(),
+                match (row.part, row.variant) {
+                    (Some(part), Some(variant)) => format!("{part} ({variant})"),
+                    (Some(part), None) => part.to_string(),
+                    (None, _) => String::new(),
+                },
+                row.answer
+                    .as_ref()
+                    .map_or_else(String::new, ToString::to_string),
+                row.elapsed
+                    .map_or_else(String::new, |elapsed| Elapsed(elapsed).to_string()),
+                row.alloc
+                    .map_or_else(String::new, |alloc| alloc.to_string()),
+                row.status.to_string(),
+            ]
+        })
+        .collect();
+    let mut widths = [0; 6];
+    for row in iter::once(&header).chain(&cells) {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+    let [day_width, part_width, answer_width, time_width, alloc_width, _] = widths;
+    // The allocations column only shows with the `alloc-stats` feature.
+    let show_alloc = rows.iter().any(|row| row.alloc.is_some());
+    for [day, part, answer, time, alloc, status] in iter::once(&header).chain(&cells) {
+        let alloc = if show_alloc {
+            format!("{alloc:<alloc_width$}  ")
+        } else {
+            String::new()
+        };
+        println!(
+            "{day:>day_width$}  {part:<part_width$}  {answer:<answer_width$}  {time:>time_width$}  {alloc}{status}"
+        );
+    }
+}
+
+/// Solves the days concurrently, one thread each, and prints a table of the answers. With the
+/// `alloc-stats` feature the days run one at a time, as their allocations are counted together.
+fn solve(entries: &[Entry], args: &Args, mode: Mode, format: Format) -> Result<bool, AocError> {
+    let mut answers = Answers::default_location()?;
+    let rows = solve_rows(entries, args, &mut answers, mode);
+    print_rows(&rows, format);
+
+    if mode == Mode::Accept {
+        answers.save()?;
+        eprintln!("recorded the answers in {}", answers.path().display());
+    }
+    if mode == Mode::Verify && !rows.iter().any(Row::checked) {
+        eprintln!("no answers were checked, record them with --accept first");
+        return Ok(false);
+    }
+    Ok(!rows.iter().any(|row| row.status.failed(mode)))
+}
+
+/// The rows of the answers to `entries`, sorted by day and part, checked against `answers` and
+/// recorded in them when accepting.
+fn solve_rows(entries: &[Entry], args: &Args, answers: &mut Answers, mode: Mode) -> Vec<Row> {
+    let mut rows = Vec::new();
+    let mut inputs = Vec::new();
+    for entry in entries {
+        if mode == Mode::Verify && answers.day(entry.day).is_none() {
+            eprintln!(
+                "day {:>2}: skipped, no known answers in {}",
+                entry.day,
+                answers.path().display()
+            );
+            continue;
+        }
+        match args.try_input(entry.puzzle()) {
+            Ok(input) => inputs.push((entry, input)),
+            Err(e) => rows.push(Row::failed(entry.day, None, None, Status::NoInput(e))),
+        }
+    }
+
+    let mut results = Vec::new();
+    thread::scope(|scope| {
+        let mut threads = Vec::new();
+        for (entry, input) in &inputs {
+            threads.push(scope.spawn(move || {
+                let mut solved = Vec::new();
+                entry.solve(input, args, &mut |part, variant, answer| {
+                    solved.push((part, variant, answer));
+                });
+                solved
+            }));
+            if cfg!(feature = "alloc-stats") {
+                // The allocations of concurrent days would be counted together.
+                results.extend(threads.drain(..).map(ScopedJoinHandle::join));
+            }
+        }
+        results.extend(threads.into_iter().map(ScopedJoinHandle::join));
+    });
+    let results = results
+        .into_iter()
+        .map(|solved| solved.expect("the parts run under catch_unwind"));
+
+    for ((entry, input), solved) in inputs.iter().zip(results) {
+        let input_sha256 = input_hash(input);
+        // The variants of a part come after its main solver, which they are checked against.
+        let mut main_answers = Vec::new();
+        for (part, variant, solved) in solved {
+            let (answer, elapsed, alloc) = match solved {
+                Ok(solved) => solved,
+                Err(failure) => {
+                    rows.push(Row {
+                        variant,
+                        ..Row::failed(
+                            entry.day,
+                            Some(part),
+                            Some(input_sha256.clone()),
+                            Status::Failed(failure),
+                        )
+                    });
+                    continue;
+                }
+            };
+            let main = main_answers
+                .iter()
+                .find_map(|(p, main): &(Part, String)| (*p == part).then_some(main));
+            let status = match main {
+                Some(main) if variant.is_some() && *main != answer.to_string() => {
+                    Status::Disagrees { main: main.clone() }
+                }
+                _ => Status::Checked(answers.check(entry.day, part, &input_sha256, &answer)),
+            };
+            if variant.is_none() {
+                main_answers.push((part, answer.to_string()));
+                if mode == Mode::Accept {
+                    answers.accept(entry.day, part, &input_sha256, &answer);
+                }
+            }
+            rows.push(Row {
+                day: entry.day,
+                part: Some(part),
+                variant,
+                answer: Some(answer),
+                elapsed: Some(elapsed),
+                alloc,
+                input_sha256: Some(input_sha256.clone()),
+                status,
+            });
+        }
+    }
+    rows.sort_by_key(|row| (row.day, row.part));
+    rows
+}
+
+/// Solves every day and rewrites the results section of [`README`] with them.
+fn readme(args: &Args, redact: bool) -> Result<bool, AocError> {
+    let mut answers = Answers::default_location()?;
+    let rows = solve_rows(DAYS, args, &mut answers, Mode::Solve);
+    let path = Path::new(README);
+    let io_error = |source| AocError::Io {
+        path: Some(path.to_path_buf()),
+        source,
+    };
+    let text = fs::read_to_string(path).map_err(io_error)?;
+    let text = replace_section(&text, &results_table(DAYS, &rows, redact)).ok_or_else(|| {
+        AocError::MissingMarkers {
+            path: path.to_path_buf(),
+            start: RESULTS_START,
+            end: RESULTS_END,
+        }
+    })?;
+    fs::write(path, text).map_err(io_error)?;
+    eprintln!("updated the results in {}", path.display());
+    Ok(!rows.iter().any(|row| row.status.failed(Mode::Solve)))
+}
+
+const README: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/README.md");
+const RESULTS_START: &str = "<!-- aoc readme start -->";
+const RESULTS_END: &str = "<!-- aoc readme end -->";
+
+/// A Markdown table with a line per day of `entries`, summarizing its `rows`.
+fn results_table(entries: &[Entry], rows: &[Row], redact: bool) -> String {
+    let mut table = "| Day | Puzzle | Implemented | Part one | Part two | Time |\n\
+                     | --: | :-- | :-: | --: | --: | --: |\n"
+        .to_string();
+    for entry in entries {
+        let rows: Vec<_> = rows.iter().filter(|row| row.day == entry.day).collect();
+        let implemented = if rows.iter().any(|row| row.part.is_none()) {
+            "?".to_string()
+        } else {
+            let parts: Vec<_> = rows
+                .iter()
+                .filter(|row| !matches!(row.status, Status::Failed(Failure::Unimplemented)))
+                .filter_map(|row| row.part.map(|part| part.number().to_string()))
+                .collect();
+            if parts.is_empty() {
+                "none".to_string()
+            } else {
+                parts.join(", ")
+            }
+        };
+        let [part_one, part_two] = Part::ALL.map(|part| {
+            // A row without a part, i.e. a missing input, stands for both; a part that was not
+            // solved at all is left empty.
+            let row = rows
+                .iter()
+                .find(|row| row.part == Some(part) && row.variant.is_none())
+                .or_else(|| rows.iter().find(|row| row.part.is_none()));
+            match row.and_then(|row| row.answer.as_ref()) {
+                Some(_) if redact => "*redacted*".to_string(),
+                Some(answer) => format!("`{answer}`"),
+                None => row.map_or_else(String::new, |row| row.status.kind().replace('_', " ")),
+            }
+        });
+        let times: Vec<_> = rows.iter().filter_map(|row| row.elapsed).collect();
+        let time = if times.is_empty() {
+            String::new()
+        } else {
+            Elapsed(times.into_iter().sum()).to_string()
+        };
+        writeln!(
+            table,
+            "| {} | [{}](https://adventofcode.com/{YEAR}/day/{}) | {implemented} | {part_one} \
+             | {part_two} | {time} |",
+            entry.day, entry.title, entry.day
+        )
+        .expect("writing to a String cannot fail");
+    }
+    table
+}
+
+/// `text` with the lines between [`RESULTS_START`] and [`RESULTS_END`] replaced by `section`, or
+/// `None` if it lacks the markers.
+fn replace_section(text: &str, section: &str) -> Option<String> {
+    let (before, rest) = text.split_once(RESULTS_START)?;
+    let (_, after) = rest.split_once(RESULTS_END)?;
+    Some(format!(
+        "{before}{RESULTS_START}\n{section}{RESULTS_END}{after}"
+    ))
+}
+
+/// Checks the solvers of `entries` against their examples, printing a line per example.
+fn examples(entries: &[Entry]) -> Result<bool, AocError> {
+    let dir = Path::new(DEFAULT_EXAMPLES_DIR);
+    let mut ok = true;
+    for entry in entries {
+        let examples = examples::load(dir, entry.day)?;
+        if examples.is_empty() {
+            println!(
+                "day {:>2}: no examples in {}",
+                entry.day,
+                examples::day_dir(dir, entry.day).display()
+            );
+        }
+        for example in examples {
+            let checked = examples::check(entry, &example);
+            ok &= checked
+                .iter()
+                .all(|checked| checked.passed() || checked.pending());
+            let summary: Vec<_> = checked.iter().map(ToString::to_string).collect();
+            let summary = if summary.is_empty() {
+                "no expected answers".to_string()
+            } else {
+                summary.join("; ")
+            };
+            println!("day {:>2} example {}: {summary}", entry.day, example.name);
+        }
+    }
+    Ok(ok)
+}
+
+/// How long changes have to settle before `watch` reruns, as editors save in bursts.
+const SETTLE: Duration = Duration::from_millis(200);
+
+/// Rebuilds and reruns the examples and input of `entry` whenever a source file, one of its
+/// examples or its `input` changes. The reruns go through `cargo run` to pick up the rebuild, and
+/// the one on the input gets `args`.
+fn watch(entry: &Entry, input: Option<&InputArg>, args: &[String]) -> Result<bool, AocError> {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let src = root.join("src");
+    let examples = Path::new(DEFAULT_EXAMPLES_DIR);
+    let day_examples = examples::day_dir(examples, entry.day);
+    let input = match input {
+        Some(InputArg::File(path)) => env::current_dir()
+            .map_err(|source| AocError::Io { path: None, source })?
+            .join(path),
+        _ => InputDir::default_location().path(entry.puzzle()),
+    };
+
+    let watch_error = |e: notify::Error| AocError::Watch(e.to_string());
+    let (sender, receiver) = mpsc::channel();
+    let mut watcher = notify::recommended_watcher(sender).map_err(watch_error)?;
+    watcher
+        .watch(&src, RecursiveMode::Recursive)
+        .map_err(watch_error)?;
+    for (dir, mode) in [
+        (examples, RecursiveMode::Recursive),
+        (input.parent().unwrap_or(root), RecursiveMode::NonRecursive),
+    ] {
+        if dir.is_dir() {
+            watcher.watch(dir, mode).map_err(watch_error)?;
+        } else {
+            eprintln!("not watching {}, which does not exist", dir.display());
+        }
+    }
+    let relevant = |path: &PathBuf| {
+        (path.starts_with(&src) && path.extension().is_some_and(|extension| extension == "rs"))
+            || path.starts_with(&day_examples)
+            || *path == input
+    };
+
+    let day = entry.day.to_string();
+    rerun(root, &day, args);
+    for event in &receiver {
+        let event = event.map_err(watch_error)?;
+        let changed = matches!(
+            event.kind,
+            EventKind::Create(_)
+                | EventKind::Remove(_)
+                | EventKind::Modify(ModifyKind::Any | ModifyKind::Data(_) | ModifyKind::Name(_))
+        );
+        let Some(path) = event.paths.iter().find(|path| relevant(path)) else {
+            continue;
+        };
+        if changed {
+            while receiver.recv_timeout(SETTLE).is_ok() {}
+            println!(
+                "\n{} changed",
+                path.strip_prefix(root).unwrap_or(path).display()
+            );
+            rerun(root, &day, args);
+        }
+    }
+    Ok(true)
+}
+
+/// Rebuilds the runner and runs the examples of `day` and then `day` with `args`, unless the
+/// build fails.
+fn rerun(root: &Path, day: &str, args: &[String]) {
+    let cargo = env::var_os("CARGO").unwrap_or_else(|| "cargo".into());
+    let cargo = |command: &str| {
+        let mut cargo = Command::new(&cargo);
+        cargo
+            .args([command, "--quiet", "--manifest-path"])
+            .arg(root.join("Cargo.toml"))
+            .args(["--bin", "aoc"]);
+        if !cfg!(debug_assertions) {
+            cargo.arg("--release");
+        }
+        cargo
+    };
+    let succeeded = |mut command: Command| {
+        command
+            .status()
+            .inspect_err(|e| eprintln!("cannot run cargo: {e}"))
+            .is_ok_and(|status| status.success())
+    };
+    if !succeeded(cargo("build")) {
+        println!("build failed");
+        return;
+    }
+    let mut examples = cargo("run");
+    examples.args(["--", "examples", day]);
+    succeeded(examples);
+    let mut input = cargo("run");
+    input.args(["--", day]).args(args);
+    succeeded(input);
+}
+
+/// The input of `entry`, or `None` after reporting why there is none.
+fn input(entry: &Entry, args: &Args) -> Option<String> {
+    args.try_input(entry.puzzle())
+        .inspect_err(|e| eprintln!("day {:>2}: {e}", entry.day))
+        .ok()
+}
+
+/// Solves the day and part given by `args` and submits the answer, unless an earlier submission
+/// tells its outcome. A correct answer is recorded as known, as with `--accept`.
+fn submit(mut args: impl Iterator<Item = String>) -> Result<bool, AocError> {
+    let (Some(day), Some(part)) = (args.next(), args.next()) else {
+        return Err(AocError::InvalidArgument(
+            "submit takes a day and a part".to_string(),
+        ));
+    };
+    let day = self::day(&day)?;
+    let entry = DAYS
+        .iter()
+        .find(|entry| entry.day == day)
+        .ok_or_else(|| AocError::InvalidArgument(format!("day {day} is not implemented")))?;
+    let part: Part = part.parse()?;
+    let args = Args::parse(args)?;
+    if args.part.is_some() || args.variants {
+        return Err(AocError::InvalidArgument(
+            "submit takes the part without --part and has no --variants".to_string(),
+        ));
+    }
+    let Some(input) = input(entry, &args) else {
+        return Ok(false);
+    };
+    let args = Args {
+        part: Some(part),
+        ..args
+    };
+    let mut solved = None;
+    entry.solve(&input, &args, &mut |_, _, result| solved = Some(result));
+    let answer = match solved.expect("the part was solved") {
+        Ok((answer, ..)) => answer,
+        Err(failure) => {
+            println!("day {:>2} {part}: {failure}", entry.day);
+            return Ok(false);
+        }
+    };
+
+    let mut submissions = Submissions::default_location()?;
+    let submission = submit::submit(
+        &Client::shared(),
+        &mut submissions,
+        entry.puzzle(),
+        part,
+        &answer,
+        || aoc_2023::cookie()?.ok_or(AocError::MissingCookie),
+    )?;
+    let known = if submission.sent {
+        submissions.save()?;
+        ""
+    } else {
+        ", known from an earlier submission"
+    };
+    println!(
+        "day {:>2} {part}: {answer}, {}{known}",
+        entry.day, submission.outcome
+    );
+    if submission.outcome != Outcome::Correct {
+        return Ok(false);
+    }
+    let mut answers = Answers::default_location()?;
+    answers.accept(entry.day, part, &input_hash(&input), &answer);
+    answers.save()?;
+    eprintln!("recorded the answer in {}", answers.path().display());
+    Ok(true)
+}
+
+fn bench(entries: &[Entry], args: &Args, runs: u32, save: bool) -> Result<bool, AocError> {
+    let mut baseline = Baseline::default_location()?;
+    let mut ok = true;
+    for entry in entries {
+        let Some(input) = input(entry, args) else {
+            ok = false;
+            continue;
+        };
+        for (phase, stats) in entry.bench(&input, args.part, runs) {
+            let stats = match stats {
+                Ok(stats) => stats,
+                Err(failure) => {
+                    ok &= failure == Failure::Unimplemented;
+                    println!("day {:>2} {:<8}  {failure}", entry.day, phase.to_string());
+                    continue;
+                }
+            };
+            let change = baseline
+                .change(entry.day, phase, &stats)
+                .map_or_else(String::new, |change| format!("  {change:+.1}%"));
+            println!(
+                "day {:>2} {:<8}  median {:>7}  min {:>7}  max {:>7}  {:>3} runs{change}",
+                entry.day,
+                phase.to_string(),
+                Elapsed(stats.median),
+                Elapsed(stats.min),
+                Elapsed(stats.max),
+                stats.runs,
+            );
+            if let Some(alloc) = stats.alloc {
+                println!("{:18}{alloc}", "");
+            }
+            if save {
+                baseline.record(entry.day, phase, &stats);
+            }
+        }
+    }
+    if save {
+        baseline.save()?;
+        println!("saved the baseline in {}", baseline.path().display());
+    }
+    Ok(ok)
+}
+
+fn main() -> ExitCode {
+    match run(env::args().skip(1).collect()) {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::FAILURE,
+        Err(e @ (AocError::InvalidArgument(_) | AocError::InvalidDayNumber(_))) => {
+            eprintln!("{e}\n{USAGE}");
+            ExitCode::from(2)
+        }
+        Err(e) => {
+            eprintln!("{e}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        replace_section, results_table, select, solve_rows, take_flag, take_value, Mode, Row,
+        Status, CSV_HEADER, RESULTS_END, RESULTS_START,
+    };
+    use aoc_2023::{
+        alloc_stats::AllocStats,
+        answers::{Answers, Check},
+        examples::DEFAULT_EXAMPLES_DIR,
+        AocError, Args, Failure, InputArg, Part, DAYS,
+    };
+    use std::{env, path::Path, time::Duration};
+
+    fn days(selector: &str) -> Vec<u8> {
+        select(selector)
+            .unwrap()
+            .into_iter()
+            .map(|entry| entry.day)
+            .collect()
+    }
+
+    #[test]
+    fn selects_days() {
+        assert_eq!(days("7"), [7]);
+        assert_eq!(days("3..=9"), [3, 4, 5, 6, 7, 8, 9]);
+        assert_eq!(days("10..=25"), [10, 11]);
+        assert_eq!(days("all"), (1..=11).collect::<Vec<_>>());
+        assert!(select("0").is_err());
+        assert!(select("24").is_err());
+        assert!(select("9..=3").is_err());
+        assert!(select("3..9").is_err());
+    }
+
+    #[test]
+    fn takes_flags_and_values() {
+        let mut args: Vec<_> = ["--runs", "3", "--save", "--part", "2"]
+            .map(String::from)
+            .into();
+        assert!(take_flag(&mut args, "--save"));
+        assert!(!take_flag(&mut args, "--accept"));
+        assert_eq!(take_value(&mut args, "--runs").unwrap(), Some("3".into()));
+        assert_eq!(args, ["--part", "2"]);
+        assert!(take_value(&mut args, "--input").unwrap().is_none());
+        args.push("--runs".into());
+        assert!(take_value(&mut args, "--runs").is_err());
+    }
+
+    #[test]
+    fn records_alloc_stats() {
+        let row = Row {
+            answer: Some(6440.into()),
+            alloc: Some(AllocStats {
+                allocations: 3,
+                bytes: 4096,
+                peak: 2048,
+            }),
+            ..Row::failed(7, Some(Part::One), None, Status::Checked(Check::Unknown))
+        };
+        let json = serde_json::to_string(&row.record()).unwrap();
+        let csv = row.record().csv();
+        if cfg!(feature = "alloc-stats") {
+            assert!(json.ends_with(r#""allocations":3,"allocated_bytes":4096,"peak_bytes":2048}"#));
+            assert_eq!(csv, "7,1,6440,,,unchecked,,,3,4096,2048");
+            assert_eq!(CSV_HEADER.split(',').count(), 11);
+        } else {
+            assert!(json.ends_with(r#""variant":null}"#));
+            assert_eq!(csv, "7,1,6440,,,unchecked,,");
+            assert_eq!(CSV_HEADER.split(',').count(), 8);
+        }
+    }
+
+    #[test]
+    fn shows_unimplemented_and_panicking_parts() {
+        let solve = |day: u8| {
+            let args = Args {
+                input: Some(InputArg::File(
+                    Path::new(DEFAULT_EXAMPLES_DIR).join(format!("{day:02}/1.txt")),
+                )),
+                ..Args::default()
+            };
+            let mut answers =
+                Answers::load(env::temp_dir().join("aoc-unimplemented-answers.toml")).unwrap();
+            let day = usize::from(day);
+            solve_rows(&DAYS[day - 1..day], &args, &mut answers, Mode::Solve)
+        };
+
+        let rows = solve(11);
+        assert_eq!(rows.len(), 2);
+        assert_eq!(rows[0].answer, Some(374.into()));
+        assert_eq!(rows[1].part, Some(Part::Two));
+        assert!(matches!(
+            rows[1].status,
+            Status::Failed(Failure::Unimplemented)
+        ));
+        assert!(!rows[1].status.failed(Mode::Solve));
+
+        // Day 10's part two panics in the setup before its `todo!()`.
+        let rows = solve(10);
+        assert_eq!(rows[0].answer, Some(4.into()));
+        assert!(matches!(
+            rows[1].status,
+            Status::Failed(Failure::Panicked { .. })
+        ));
+        assert!(rows[1].status.failed(Mode::Solve));
+    }
+
+    /// `json`, a record without allocation stats, with the empty fields the `alloc-stats` feature
+    /// adds.
+    fn json(json: &str) -> String {
+        if cfg!(feature = "alloc-stats") {
+            let fields = r#","allocations":null,"allocated_bytes":null,"peak_bytes":null}"#;
+            format!("{}{fields}", json.strip_suffix('}').unwrap())
+        } else {
+            json.to_string()
+        }
+    }
+
+    /// Like [`json`], for a CSV record.
+    fn csv(csv: &str) -> String {
+        if cfg!(feature = "alloc-stats") {
+            format!("{csv},,,")
+        } else {
+            csv.to_string()
+        }
+    }
+
+    #[test]
+    fn records_rows() {
+        let row = Row {
+            day: 7,
+            part: Some(Part::Two),
+            variant: None,
+            answer: Some(5905.into()),
+            elapsed: Some(Duration::from_micros(3)),
+            alloc: None,
+            input_sha256: Some("ab".to_string()),
+            status: Status::Checked(Check::Correct),
+        };
+        assert_eq!(
+            serde_json::to_string(&row.record()).unwrap(),
+            json(
+                r#"{"day":7,"part":2,"answer":"5905","time_ns":3000,"input_sha256":"ab","status":"ok","error":null,"variant":null}"#
+            )
+        );
+        assert_eq!(row.record().csv(), csv("7,2,5905,3000,ab,ok,,"));
+        assert!(row.checked());
+        let row = Row::failed(
+            10,
+            Some(Part::Two),
+            None,
+            Status::Failed(Failure::Panicked {
+                message: "a, \"b\"".into(),
+                location: None,
+            }),
+        );
+        assert_eq!(row.record().csv(), csv(r#"10,2,,,,panicked,"a, ""b""","#));
+        assert!(!row.checked());
+        let row = Row {
+            variant: Some("dp"),
+            answer: Some(31.into()),
+            ..Row::failed(
+                4,
+                Some(Part::Two),
+                None,
+                Status::Disagrees {
+                    main: "30".to_string(),
+                },
+            )
+        };
+        assert_eq!(
+            row.record().csv(),
+            csv("4,2,31,,,disagrees,the main solver's answer is 30,dp")
+        );
+    }
+
+    #[test]
+    fn writes_results_table() {
+        let row = |part, answer: Option<u32>, status| Row {
+            answer: answer.map(Into::into),
+            elapsed: answer.map(|_| Duration::from_millis(2)),
+            ..Row::failed(10, Some(part), None, status)
+        };
+        let rows = [
+            Row {
+                day: 9,
+                ..row(Part::One, Some(114), Status::Checked(Check::Correct))
+            },
+            row(Part::One, Some(6842), Status::Checked(Check::Correct)),
+            row(Part::Two, None, Status::Failed(Failure::Unimplemented)),
+            Row::failed(11, None, None, Status::NoInput(AocError::MissingCookie)),
+        ];
+        let table = results_table(&DAYS[8..], &rows, false);
+        assert_eq!(
+            table.lines().skip(2).collect::<Vec<_>>(),
+            [
+                "| 9 | [Mirage Maintenance](https://adventofcode.com/2023/day/9) | 1 | `114` |  | 2.00ms |",
+                "| 10 | [Pipe Maze](https://adventofcode.com/2023/day/10) | 1 | `6842` | unimplemented | 2.00ms |",
+                "| 11 | [Cosmic Expansion](https://adventofcode.com/2023/day/11) | ? | no input | no input |  |",
+            ]
+        );
+        assert!(results_table(&DAYS[9..10], &rows, true).contains("| *redacted* |"));
+
+        let text = format!("# aoc\n{RESULTS_START}\nold\n{RESULTS_END}\nrest\n");
+        assert_eq!(
+            replace_section(&text, "new\n").unwrap(),
+            format!("# aoc\n{RESULTS_START}\nnew\n{RESULTS_END}\nrest\n")
+        );
+        assert_eq!(replace_section("# aoc\n", "new\n"), None);
+    }
+}
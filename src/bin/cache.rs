@@ -0,0 +1,93 @@
+use aoc_2023::{AocError, InputCache, Integrity, Puzzle};
+use std::{env, process::ExitCode};
+
+const USAGE: &str = "usage: cache list | show <day> | verify [day] | purge <day>
+  <day> is a day of this year, e.g. `7`, or of another year, e.g. `2022/7`";
+
+fn list(cache: &InputCache) -> Result<(), AocError> {
+    for entry in cache.entries()? {
+        let size = entry
+            .size
+            .map_or_else(|| "missing".to_string(), |size| format!("{size} bytes"));
+        println!(
+            "{:<12} {size:>14}  {}",
+            entry.puzzle.to_string(),
+            entry.path.display()
+        );
+    }
+    Ok(())
+}
+
+fn show(cache: &InputCache, puzzle: Puzzle) -> Result<bool, AocError> {
+    if let Some(input) = cache.read(puzzle)? {
+        println!("{}", input.trim_end_matches('\n'));
+        Ok(true)
+    } else {
+        eprintln!("{puzzle} is not cached");
+        Ok(false)
+    }
+}
+
+fn verify(cache: &InputCache, puzzles: &[Puzzle]) -> Result<bool, AocError> {
+    let mut intact = true;
+    for &puzzle in puzzles {
+        match cache.verify(puzzle)? {
+            None => {
+                eprintln!("{puzzle}: not cached");
+                intact = false;
+            }
+            Some(Integrity::Intact) => println!("{puzzle}: ok"),
+            Some(Integrity::Unhashed) => println!("{puzzle}: no stored hash"),
+            Some(Integrity::Missing) => {
+                println!("{puzzle}: MISSING, purge it to download it again");
+                intact = false;
+            }
+            Some(Integrity::Modified { stored, actual }) => {
+                println!("{puzzle}: MODIFIED, stored {stored}, now {actual}");
+                intact = false;
+            }
+        }
+    }
+    Ok(intact)
+}
+
+fn purge(cache: &InputCache, puzzle: Puzzle) -> Result<bool, AocError> {
+    if cache.remove(puzzle)? {
+        println!("purged {puzzle}");
+        Ok(true)
+    } else {
+        eprintln!("{puzzle} is not cached");
+        Ok(false)
+    }
+}
+
+fn run(args: &[String]) -> Result<bool, AocError> {
+    let cache = InputCache::default_location();
+    let args: Vec<_> = args.iter().map(String::as_str).collect();
+    match args[..] {
+        ["list"] => list(&cache).map(|()| true),
+        ["show", puzzle] => show(&cache, puzzle.parse()?),
+        ["verify"] => {
+            let puzzles: Vec<_> = cache.entries()?.into_iter().map(|e| e.puzzle).collect();
+            verify(&cache, &puzzles)
+        }
+        ["verify", puzzle] => verify(&cache, &[puzzle.parse()?]),
+        ["purge", puzzle] => purge(&cache, puzzle.parse()?),
+        _ => {
+            eprintln!("{USAGE}");
+            Ok(false)
+        }
+    }
+}
+
+fn main() -> ExitCode {
+    let args: Vec<_> = env::args().skip(1).collect();
+    match run(&args) {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::FAILURE,
+        Err(e) => {
+            eprintln!("{e}");
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,162 @@
+//! Day 1: Trebuchet?!
+#![allow(clippy::missing_panics_doc)]
+
+use crate::{match_and_move_1, Answer, Solution};
+use winnow::combinator::repeat_till0;
+use winnow::stream::Accumulate;
+use winnow::token::any;
+use winnow::{
+    combinator::{repeat, Alt},
+    token::one_of,
+    PResult, Parser,
+};
+
+#[must_use]
+pub fn calibration_sum(input: &str) -> u32 {
+    let mut buffer = String::with_capacity(2);
+
+    input
+        .lines()
+        .map(|line| {
+            let first = line
+                .chars()
+                .filter(char::is_ascii_digit)
+                .take(1)
+                .next()
+                .unwrap();
+            let last = line
+                .chars()
+                .rev()
+                .filter(char::is_ascii_digit)
+                .take(1)
+                .next()
+                .unwrap();
+            buffer.clear();
+            buffer.push(first);
+            buffer.push(last);
+            buffer.parse::<u32>().unwrap()
+        })
+        .sum()
+}
+
+fn digit_char(input: &mut &str) -> PResult<char> {
+    one_of(('0'..='9',)).parse_next(input)
+}
+
+fn digit_word(input: &mut &str) -> PResult<char> {
+    (
+        "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+    )
+        .choice(input)
+        .map(|word| {
+            digit_word_as_char(word)
+                .expect("a successful match on the preceding choice will return a char")
+        })
+}
+
+fn digit_word_as_char(word: &str) -> Option<char> {
+    match word {
+        "one" => Some('1'),
+        "two" => Some('2'),
+        "three" => Some('3'),
+        "four" => Some('4'),
+        "five" => Some('5'),
+        "six" => Some('6'),
+        "seven" => Some('7'),
+        "eight" => Some('8'),
+        "nine" => Some('9'),
+        _ => None,
+    }
+}
+
+fn digit(input: &mut &str) -> PResult<char> {
+    (digit_char, digit_word).choice(input)
+}
+
+struct FirstAndLast<T> {
+    first: Option<T>,
+    last: Option<T>,
+}
+
+impl<T: Copy> FirstAndLast<T> {
+    fn first(&self) -> T {
+        self.first.unwrap()
+    }
+    fn last(&self) -> T {
+        self.last.unwrap_or(self.first())
+    }
+}
+
+impl<T> Accumulate<T> for FirstAndLast<T> {
+    fn initial(_capacity: Option<usize>) -> Self {
+        Self {
+            first: None,
+            last: None,
+        }
+    }
+
+    fn accumulate(&mut self, acc: T) {
+        if self.first.is_none() {
+            self.first = Some(acc);
+        } else {
+            self.last = Some(acc);
+        }
+    }
+}
+
+#[must_use]
+pub fn calibration_sum_with_words(input: &str) -> u32 {
+    let mut buffer = String::with_capacity(2);
+    input
+        .lines()
+        .map(|mut line| {
+            let first_and_last: FirstAndLast<_> = repeat(
+                1..,
+                repeat_till0(any, match_and_move_1(digit)).map(|((), c)| c),
+            )
+            .parse_next(&mut line)
+            .unwrap();
+            first_and_last
+        })
+        .map(|digits| {
+            buffer.clear();
+            buffer.push(digits.first());
+            buffer.push(digits.last());
+            buffer.parse::<u32>().unwrap()
+        })
+        .sum()
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Parsed<'i> = &'i str;
+
+    fn parse(input: &str) -> &str {
+        input
+    }
+
+    fn part_one(input: &&str) -> Answer {
+        calibration_sum(input).into()
+    }
+
+    fn part_two(input: &&str) -> Answer {
+        calibration_sum_with_words(input).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::digit_word;
+    use winnow::Parser;
+
+    #[test]
+    fn digit_word_err() {
+        let input = &mut "";
+        assert!(digit_word.parse_next(input).is_err());
+    }
+    #[test]
+    fn digit_word_ok() {
+        assert_eq!(digit_word.parse_next(&mut "one"), Ok('1'));
+    }
+}
@@ -0,0 +1,172 @@
+//! Day 2: Cube Conundrum
+#![allow(clippy::missing_panics_doc)]
+
+use crate::{Answer, Solution};
+
+#[derive(Debug)]
+pub struct Game {
+    id: u32,
+    max: Max,
+}
+
+impl Game {
+    fn from(id: u32, sets: &[Set]) -> Self {
+        Self {
+            id,
+            max: Max::from_sets(sets),
+        }
+    }
+
+    #[must_use]
+    pub fn valid(&self, total_reds: u32, total_greens: u32, total_blues: u32) -> bool {
+        self.max.reds <= total_reds
+            && self.max.greens <= total_greens
+            && self.max.blues <= total_blues
+    }
+
+    #[must_use]
+    pub fn power(&self) -> u32 {
+        self.max.reds * self.max.greens * self.max.blues
+    }
+}
+
+#[derive(Default, Debug)]
+struct Set {
+    reds: u32,
+    greens: u32,
+    blues: u32,
+}
+
+#[derive(Default, Debug)]
+struct Max {
+    reds: u32,
+    greens: u32,
+    blues: u32,
+}
+
+impl Max {
+    fn from_sets(sets: &[Set]) -> Self {
+        let mut max = Self::default();
+        for set in sets {
+            max.reds = max.reds.max(set.reds);
+            max.greens = max.greens.max(set.greens);
+            max.blues = max.blues.max(set.blues);
+        }
+        max
+    }
+}
+
+mod w {
+    use super::{Game, Set};
+    use winnow::{
+        ascii::dec_uint,
+        combinator::{alt, separated},
+        PResult, Parser,
+    };
+
+    pub fn count(color: &str) -> impl FnMut(&mut &str) -> PResult<u32> + '_ {
+        move |input| {
+            (dec_uint, " ", color)
+                .map(|(count, _, _)| count)
+                .parse_next(input)
+        }
+    }
+
+    pub fn set(input: &mut &str) -> PResult<Set> {
+        enum ColorCount {
+            Red(u32),
+            Green(u32),
+            Blue(u32),
+        }
+        let blue = count("blue").map(ColorCount::Blue);
+        let red = count("red").map(ColorCount::Red);
+        let green = count("green").map(ColorCount::Green);
+        let mut colors = separated(1..=3, alt((blue, red, green)), ", ");
+        let counts: Vec<ColorCount> = colors.parse_next(input)?;
+        let mut set = Set::default();
+
+        for count in counts {
+            match count {
+                ColorCount::Red(c) => {
+                    set.reds = c;
+                }
+                ColorCount::Green(c) => {
+                    set.greens = c;
+                }
+                ColorCount::Blue(c) => {
+                    set.blues = c;
+                }
+            }
+        }
+
+        Ok(set)
+    }
+
+    pub fn sets(input: &mut &str) -> PResult<Vec<Set>> {
+        separated(1.., set, "; ").parse_next(input)
+    }
+
+    pub fn id(input: &mut &str) -> PResult<u32> {
+        ("Game ", dec_uint).map(|(_, id)| id).parse_next(input)
+    }
+
+    pub fn game(input: &mut &str) -> PResult<Game> {
+        (id, ": ", sets)
+            .map(|(id, _, sets)| Game::from(id, &sets))
+            .parse_next(input)
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn parses_id() {
+            assert_eq!(id(&mut "Game 42"), Ok(42));
+        }
+
+        #[test]
+        fn parses_count() {
+            let mut red = count("red");
+            assert_eq!(red.parse_next(&mut "30 red"), Ok(30));
+        }
+    }
+}
+
+#[must_use]
+pub fn parse_games(input: &str) -> Vec<Game> {
+    input
+        .lines()
+        .map(|mut line| w::game(&mut line).unwrap())
+        .collect()
+}
+
+#[must_use]
+pub fn get_sum_of_valid_game_ids(games: &[Game]) -> u32 {
+    games
+        .iter()
+        .filter_map(|game| game.valid(12, 13, 14).then_some(game.id))
+        .sum()
+}
+
+pub fn get_power_of_all_games(games: &[Game]) -> u32 {
+    games.iter().map(Game::power).sum()
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Parsed<'i> = Vec<Game>;
+
+    fn parse(input: &str) -> Vec<Game> {
+        parse_games(input)
+    }
+
+    fn part_one(games: &Vec<Game>) -> Answer {
+        get_sum_of_valid_game_ids(games).into()
+    }
+
+    fn part_two(games: &Vec<Game>) -> Answer {
+        get_power_of_all_games(games).into()
+    }
+}
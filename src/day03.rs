@@ -0,0 +1,191 @@
+//! Day 3: Gear Ratios
+#![allow(clippy::missing_panics_doc)]
+
+use crate::{Answer, Solution};
+use std::ops::{Range, RangeInclusive, Sub};
+use winnow::ascii::dec_uint;
+use winnow::combinator::{alt, repeat, repeat_till0};
+use winnow::error::ContextError;
+use winnow::token::any;
+use winnow::{Located, Parser};
+
+#[derive(Debug)]
+struct NumberLocation {
+    row: usize,
+    columns_plus: RangeInclusive<usize>,
+}
+
+#[derive(Debug)]
+pub struct Number {
+    number: u32,
+    location: NumberLocation,
+}
+
+fn to_inclusive<Idx: Sub<Idx, Output = Idx> + From<u8>>(range: Range<Idx>) -> RangeInclusive<Idx> {
+    RangeInclusive::new(range.start, range.end - 1u8.into())
+}
+
+impl NumberLocation {
+    fn from(row: usize, columns: RangeInclusive<usize>) -> Self {
+        Self {
+            row,
+            columns_plus: {
+                RangeInclusive::new(columns.start().saturating_sub(1), columns.end() + 1)
+            },
+        }
+    }
+    fn adjacent_to(&self, symbol_location: &SymbolLocation) -> bool {
+        let row_adjacent = self.row == symbol_location.row
+            || (self.row + 1) == symbol_location.row
+            || self.row == (symbol_location.row + 1);
+        row_adjacent && self.columns_plus.contains(&symbol_location.column)
+    }
+}
+
+impl SymbolLocation {
+    #[allow(dead_code)]
+    fn adjacent_to(&self, number_location: &NumberLocation) -> bool {
+        number_location.adjacent_to(self)
+    }
+}
+
+#[derive(Debug)]
+struct SymbolLocation {
+    row: usize,
+    column: usize,
+}
+
+#[derive(Debug)]
+pub struct Symbol {
+    symbol: char,
+    location: SymbolLocation,
+}
+
+#[must_use]
+pub fn get_part_numbers_and_symbols(input: &str) -> (Vec<Number>, Vec<Symbol>) {
+    enum Entity {
+        Symbol(Symbol),
+        Number(Number),
+    }
+    let entities = input
+        .lines()
+        .enumerate()
+        .map(|(row, line)| {
+            let mut line = Located::new(line);
+            let symbol = any::<_, ContextError>
+                .verify(|&c| c != '.')
+                .with_span()
+                .map(|(symbol, range)| {
+                    Entity::Symbol(Symbol {
+                        symbol,
+                        location: SymbolLocation {
+                            row,
+                            column: range.start,
+                        },
+                    })
+                });
+            let part_number = dec_uint.with_span().map(|(number, span)| {
+                Entity::Number(Number {
+                    number,
+                    location: NumberLocation::from(row, to_inclusive(span)),
+                })
+            });
+            let nothing = '.'.void();
+            let something = alt((part_number, symbol));
+            let entities: Vec<Entity> = repeat(
+                ..,
+                repeat_till0(nothing, something).map(|((), entity): ((), _)| entity),
+            )
+            .parse_next(&mut line)
+            .unwrap();
+            entities
+        })
+        .reduce(|mut acc, entities| {
+            acc.extend(entities);
+            acc
+        })
+        .unwrap();
+    let mut symbols = Vec::new();
+    let mut part_numbers = Vec::new();
+    for entity in entities {
+        match entity {
+            Entity::Symbol(symbol) => {
+                symbols.push(symbol);
+            }
+            Entity::Number(number) => {
+                part_numbers.push(number);
+            }
+        }
+    }
+    (part_numbers, symbols)
+}
+
+#[must_use]
+pub fn get_sum_of_part_numbers((part_numbers, symbols): &(Vec<Number>, Vec<Symbol>)) -> u32 {
+    part_numbers
+        .iter()
+        .filter_map(|number| {
+            symbols
+                .iter()
+                .any(|symbol| number.location.adjacent_to(&symbol.location))
+                .then_some(number.number)
+        })
+        .sum()
+}
+
+#[must_use]
+pub fn get_sum_of_gear_ratios((part_numbers, symbols): &(Vec<Number>, Vec<Symbol>)) -> u32 {
+    symbols
+        .iter()
+        .filter_map(|symbol| (symbol.symbol == '*').then_some(&symbol.location))
+        .filter_map(|symbol_location| {
+            let mut gear_ratio = 1;
+            let mut count = 0;
+            for part_number in part_numbers {
+                if !part_number.location.adjacent_to(symbol_location) {
+                    continue;
+                }
+                count += 1;
+                if count > 2 {
+                    return None;
+                }
+                gear_ratio *= part_number.number;
+            }
+            if count == 2 {
+                Some(gear_ratio)
+            } else {
+                None
+            }
+        })
+        .sum()
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Parsed<'i> = (Vec<Number>, Vec<Symbol>);
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        get_part_numbers_and_symbols(input)
+    }
+
+    fn part_one(schematic: &Self::Parsed<'_>) -> Answer {
+        get_sum_of_part_numbers(schematic).into()
+    }
+
+    fn part_two(schematic: &Self::Parsed<'_>) -> Answer {
+        get_sum_of_gear_ratios(schematic).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::to_inclusive;
+    use std::ops::{Range, RangeInclusive};
+
+    #[test]
+    fn to_inclusive_works() {
+        let original = Range { start: 10, end: 20 };
+        assert_eq!(to_inclusive(original), RangeInclusive::new(10, 19));
+    }
+}
@@ -0,0 +1,176 @@
+//! Day 4: Scratchcards
+#![allow(clippy::missing_panics_doc)]
+
+use crate::{Answer, Part, Solution, Variant};
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::str::FromStr;
+use winnow::ascii::{dec_uint, space1};
+use winnow::combinator::separated;
+use winnow::{PResult, Parser};
+
+struct WinningNumbers(HashSet<u32>);
+struct OwnNumbers(HashSet<u32>);
+
+pub struct CardWithId {
+    id: u32,
+    card: Card,
+}
+
+pub struct Card {
+    winning_numbers: WinningNumbers,
+    own_numbers: OwnNumbers,
+}
+
+impl Card {
+    #[must_use]
+    pub fn winning(&self) -> u32 {
+        u32::try_from(
+            self.own_numbers
+                .0
+                .intersection(&self.winning_numbers.0)
+                .count(),
+        )
+        .unwrap()
+    }
+    #[must_use]
+    pub fn points(&self) -> u32 {
+        let winning = self.winning();
+        if winning == 0 {
+            return 0;
+        }
+        2u32.pow(winning - 1)
+    }
+}
+
+impl FromStr for CardWithId {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        fn hs(input: &mut &str) -> PResult<HashSet<u32>> {
+            separated(1.., dec_uint, space1).parse_next(input)
+        }
+        let winning = hs.map(WinningNumbers);
+        let own = hs.map(OwnNumbers);
+        (
+            "Card".void(),
+            space1.void(),
+            dec_uint,
+            ":".void(),
+            space1.void(),
+            winning,
+            space1.void(),
+            "|".void(),
+            space1.void(),
+            own,
+        )
+            .map(
+                |((), (), id, (), (), winning_numbers, (), (), (), own_numbers)| CardWithId {
+                    id,
+                    card: Card {
+                        winning_numbers,
+                        own_numbers,
+                    },
+                },
+            )
+            .parse(s)
+            .map_err(|e| e.to_string())
+    }
+}
+
+#[must_use]
+pub fn parse_cards(input: &str) -> Vec<CardWithId> {
+    input.lines().map(|line| line.parse().unwrap()).collect()
+}
+
+#[must_use]
+pub fn points(cards: &[CardWithId]) -> u32 {
+    cards
+        .iter()
+        .map(|card_with_id| card_with_id.card.points())
+        .sum()
+}
+
+fn to_map<'c>(cards: impl IntoIterator<Item = &'c CardWithId>) -> HashMap<u32, &'c Card> {
+    let cards = cards.into_iter();
+    let mut map = if let Some(size) = cards.size_hint().1 {
+        HashMap::with_capacity(size)
+    } else {
+        HashMap::new()
+    };
+    for card in cards {
+        map.insert(card.id, &card.card);
+    }
+    map
+}
+
+#[must_use]
+pub fn count_cards(cards: &[CardWithId]) -> u32 {
+    let cards = to_map(cards);
+    let mut count = 0;
+    let mut unchecked: VecDeque<_> = cards.keys().copied().collect();
+
+    while let Some(id) = unchecked.pop_front() {
+        count += 1;
+        let wins = cards.get(&id).unwrap().winning();
+        for idx in (1..=wins).rev() {
+            let copied_id = id + idx;
+            unchecked.push_front(copied_id);
+        }
+    }
+    count
+}
+
+/// Counts the cards like [`count_cards`], but in one pass over them: each card adds its copies to
+/// the cards it wins copies of.
+#[must_use]
+pub fn count_cards_dp(cards: &[CardWithId]) -> u32 {
+    let mut copies = vec![1; cards.len()];
+    for (i, card) in cards.iter().enumerate() {
+        let wins = usize::try_from(card.card.winning()).unwrap();
+        let (won, later) = copies.split_at_mut(i + 1);
+        for copy in later.iter_mut().take(wins) {
+            *copy += won[i];
+        }
+    }
+    copies.iter().sum()
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Parsed<'i> = Vec<CardWithId>;
+
+    const VARIANTS: &'static [Variant<Self>] = &[Variant {
+        part: Part::Two,
+        name: "dp",
+        solve: |cards| count_cards_dp(cards).into(),
+    }];
+
+    fn parse(input: &str) -> Vec<CardWithId> {
+        parse_cards(input)
+    }
+
+    fn part_one(cards: &Vec<CardWithId>) -> Answer {
+        points(cards).into()
+    }
+
+    fn part_two(cards: &Vec<CardWithId>) -> Answer {
+        count_cards(cards).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::parse_cards;
+
+    const TEST_INPUT: &str = "Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
+Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
+Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
+Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
+Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11";
+    #[test]
+    fn test_parse_cards() {
+        let cards = parse_cards(TEST_INPUT);
+        assert_eq!(cards.len(), 6);
+    }
+}
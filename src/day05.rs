@@ -0,0 +1,232 @@
+//! Day 5: If You Give A Seed A Fertilizer
+#![allow(clippy::missing_panics_doc)]
+
+use crate::{Answer, Part, Solution, Variant};
+use rayon::iter::{IntoParallelIterator, ParallelIterator};
+use std::ops::Range;
+use std::str::FromStr;
+use winnow::combinator::separated_pair;
+use winnow::{
+    ascii::dec_uint,
+    combinator::{alt, rest, separated},
+    error::{ContextError, ParseError},
+    token::take_until1,
+    PResult, Parser,
+};
+
+#[derive(Debug)]
+pub struct Special {
+    source_start: u32,
+    destination_start: u32,
+    length: u32,
+}
+
+impl Special {
+    fn get(&self, source: u32) -> Option<u32> {
+        if source < self.source_start {
+            None
+        } else {
+            let diff = source - self.source_start;
+            if diff >= self.length {
+                None
+            } else {
+                Some(self.destination_start + diff)
+            }
+        }
+    }
+}
+
+impl FromStr for Special {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (destination_start, _, source_start, _, length): (u32, _, u32, _, _) =
+            (dec_uint, ' ', dec_uint, ' ', dec_uint)
+                .parse(s)
+                .map_err(|e: ParseError<_, ContextError>| e.to_string())?;
+        Ok(Self {
+            source_start,
+            destination_start,
+            length,
+        })
+    }
+}
+
+#[derive(Default, Debug)]
+pub struct Map {
+    special: Vec<Special>,
+}
+
+impl Map {
+    fn get(&self, key: u32) -> u32 {
+        self.special
+            .iter()
+            .find_map(|special| special.get(key))
+            .unwrap_or(key)
+    }
+
+    /// What the keys in `ranges` map to, as ranges split where the special ranges start and end.
+    fn get_ranges(&self, mut ranges: Vec<Range<u64>>) -> Vec<Range<u64>> {
+        let mut mapped = Vec::new();
+        for special in &self.special {
+            let start = u64::from(special.source_start);
+            let end = start + u64::from(special.length);
+            let destination = u64::from(special.destination_start);
+            let mut unmapped = Vec::new();
+            for range in ranges {
+                let overlap = range.start.max(start)..range.end.min(end);
+                if overlap.is_empty() {
+                    unmapped.push(range);
+                    continue;
+                }
+                mapped.push(overlap.start - start + destination..overlap.end - start + destination);
+                if range.start < overlap.start {
+                    unmapped.push(range.start..overlap.start);
+                }
+                if overlap.end < range.end {
+                    unmapped.push(overlap.end..range.end);
+                }
+            }
+            ranges = unmapped;
+        }
+        mapped.extend(ranges);
+        mapped
+    }
+}
+
+impl FromStr for Map {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut map = Self::default();
+        for line in s.lines() {
+            map.special.push(line.parse()?);
+        }
+        Ok(map)
+    }
+}
+
+/// The maps from seeds to soil through to humidity to location, which follow the seeds.
+fn maps(input: &mut &str) -> Vec<Map> {
+    fn a_map(input: &mut &str) -> PResult<Map> {
+        let map_header = ("\n\n", take_until1(":"), ":\n").void();
+        let map = alt((take_until1("\n\n"), rest));
+        (map_header, map)
+            .map(|((), map)| map)
+            .map(|input| Map::from_str(input).unwrap())
+            .parse_next(input)
+    }
+
+    (0..7).map(|_| a_map.parse_next(input).unwrap()).collect()
+}
+
+fn get_closest_location(seeds: impl IntoParallelIterator<Item = u32>, mut input: &str) -> u32 {
+    let maps = maps(&mut input);
+    seeds
+        .into_par_iter()
+        .map(|seed| maps.iter().fold(seed, |key, map| map.get(key)))
+        .min()
+        .unwrap()
+}
+
+/// Like [`get_closest_location`], but mapping whole ranges of seeds at once.
+fn get_closest_location_of_ranges(seeds: Vec<Range<u64>>, mut input: &str) -> u64 {
+    maps(&mut input)
+        .iter()
+        .fold(seeds, |ranges, map| map.get_ranges(ranges))
+        .into_iter()
+        .map(|range| range.start)
+        .min()
+        .unwrap()
+}
+
+fn seeds_singles(input: &mut &str) -> Vec<u32> {
+    fn seeds(input: &mut &str) -> PResult<Vec<u32>> {
+        ("seeds: ".void(), separated(1.., dec_uint::<_, u32, _>, ' '))
+            .map(|((), seeds)| seeds)
+            .parse_next(input)
+    }
+    seeds.parse_next(input).unwrap()
+}
+
+/// The starts and lengths of the seed ranges.
+fn seed_ranges(input: &mut &str) -> Vec<(u32, u32)> {
+    fn parser(input: &mut &str) -> PResult<Vec<(u32, u32)>> {
+        let range = separated_pair(dec_uint, ' ', dec_uint);
+        ("seeds: ".void(), separated(1.., range, ' '))
+            .map(|((), ranges)| ranges)
+            .parse_next(input)
+    }
+    parser.parse_next(input).unwrap()
+}
+
+fn seeds_ranges(input: &mut &str) -> impl ParallelIterator<Item = u32> {
+    seed_ranges(input)
+        .into_par_iter()
+        .flat_map(|(start, length)| start..start + length)
+}
+
+#[must_use]
+pub fn part_one_work(mut input: &str) -> u32 {
+    let input = &mut input;
+    let seeds = seeds_singles(input);
+    get_closest_location(seeds, input)
+}
+
+#[must_use]
+pub fn part_two_work(mut input: &str) -> u32 {
+    let input = &mut input;
+    let seeds = seeds_ranges(input);
+    get_closest_location(seeds, input)
+}
+
+/// Solves part one like [`part_one_work`], but through [`get_closest_location_of_ranges`].
+#[must_use]
+pub fn part_one_intervals(mut input: &str) -> u64 {
+    let input = &mut input;
+    let seeds = seeds_singles(input)
+        .into_iter()
+        .map(|seed| u64::from(seed)..u64::from(seed) + 1)
+        .collect();
+    get_closest_location_of_ranges(seeds, input)
+}
+
+/// Solves part two like [`part_two_work`], but maps the seed ranges instead of every seed.
+#[must_use]
+pub fn part_two_intervals(mut input: &str) -> u64 {
+    let input = &mut input;
+    let seeds = seed_ranges(input)
+        .into_iter()
+        .map(|(start, length)| u64::from(start)..u64::from(start) + u64::from(length))
+        .collect();
+    get_closest_location_of_ranges(seeds, input)
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Parsed<'i> = &'i str;
+
+    const VARIANTS: &'static [Variant<Self>] = &[
+        Variant {
+            part: Part::One,
+            name: "intervals",
+            solve: |input| part_one_intervals(input).into(),
+        },
+        Variant {
+            part: Part::Two,
+            name: "intervals",
+            solve: |input| part_two_intervals(input).into(),
+        },
+    ];
+
+    fn parse(input: &str) -> &str {
+        input
+    }
+
+    fn part_one(input: &&str) -> Answer {
+        part_one_work(input).into()
+    }
+
+    fn part_two(input: &&str) -> Answer {
+        part_two_work(input).into()
+    }
+}
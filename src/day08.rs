@@ -0,0 +1,192 @@
+//! Day 8: Haunted Wasteland
+#![allow(clippy::missing_panics_doc)]
+
+use crate::{Answer, Solution};
+use num::Integer;
+use std::{collections::HashMap, iter::repeat, str::FromStr};
+use winnow::{
+    combinator::{delimited, rest, separated_pair},
+    error::ContextError,
+    stream::AsChar,
+    token::take_while,
+    PResult, Parser,
+};
+
+pub struct Directions(Vec<Direction>);
+
+impl FromStr for Directions {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self(
+            s.chars()
+                .map(Direction::try_from)
+                .collect::<Result<Vec<_>, _>>()?,
+        ))
+    }
+}
+
+#[derive(Eq, PartialEq, Hash, Copy, Clone, Debug)]
+pub struct Name<'a>(&'a str);
+impl Name<'_> {
+    fn is_start(&self) -> bool {
+        self.0.ends_with('A')
+    }
+    fn is_goal(&self) -> bool {
+        self.0.ends_with('Z')
+    }
+}
+
+#[derive(Debug)]
+pub struct Node<'a> {
+    name: Name<'a>,
+    left: Name<'a>,
+    right: Name<'a>,
+}
+
+#[derive(Debug)]
+struct Children<'a> {
+    left: Name<'a>,
+    right: Name<'a>,
+}
+
+#[derive(Clone)]
+pub enum Direction {
+    Left,
+    Right,
+}
+
+impl TryFrom<char> for Direction {
+    type Error = String;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        Ok(match value {
+            'L' => Direction::Left,
+            'R' => Direction::Right,
+            c => return Err(format!("{c} does not correspond to a direction")),
+        })
+    }
+}
+
+impl<'a> From<&'a str> for Name<'a> {
+    fn from(value: &'a str) -> Self {
+        Self(value)
+    }
+}
+
+impl<'a> TryFrom<&'a str> for Node<'a> {
+    type Error = String;
+
+    fn try_from(value: &'a str) -> Result<Self, Self::Error> {
+        fn name<'a>(input: &mut &'a str) -> PResult<&'a str> {
+            take_while(1.., AsChar::is_alphanum).parse_next(input)
+        }
+        let (name, left, right) = (
+            name,
+            " = ".void(),
+            delimited('(', separated_pair(name, ", ", name), ')'),
+        )
+            .map(|(name, (), (left, right))| (name, left, right))
+            .parse(value)
+            .map_err(|e| e.to_string())?;
+        let node = Node {
+            name: Name(name),
+            left: Name(left),
+            right: Name(right),
+        };
+        Ok(node)
+    }
+}
+
+pub struct Network<'a> {
+    directions: Directions,
+    map: HashMap<Name<'a>, Children<'a>>,
+}
+
+pub fn parse_network(input: &str) -> Network<'_> {
+    let (directions, (), nodes) = (
+        take_while(1.., AsChar::is_alpha),
+        "\n\n".void(),
+        rest::<_, ContextError>,
+    )
+        .parse(input)
+        .unwrap();
+    let directions: Directions = directions.parse().unwrap();
+    let nodes: Vec<_> = nodes
+        .lines()
+        .map(Node::try_from)
+        .collect::<Result<_, _>>()
+        .unwrap();
+    let mut map = HashMap::with_capacity(nodes.len());
+    for Node { name, left, right } in nodes {
+        map.insert(name, Children { left, right });
+    }
+    Network { directions, map }
+}
+
+#[must_use]
+pub fn part_one_work(Network { directions, map }: &Network<'_>) -> u32 {
+    let goal = Name("ZZZ");
+
+    let mut current = Name("AAA");
+
+    let mut directions = repeat(directions.0.iter()).flatten();
+    let mut steps = 0;
+
+    while current != goal {
+        steps += 1;
+        let children = map.get(&current).unwrap();
+        current = match directions.next().unwrap() {
+            Direction::Left => children.left,
+            Direction::Right => children.right,
+        };
+    }
+
+    steps
+}
+
+pub fn part_two_work(Network { directions, map }: &Network<'_>) -> u64 {
+    let currents = map.keys().copied().filter(Name::is_start);
+
+    let directions = repeat(directions.0.iter()).flatten();
+
+    let mut lcm = 1;
+
+    for mut current in currents {
+        let mut directions = directions.clone();
+        let mut step = 0;
+        while !current.is_goal() {
+            step += 1;
+            let direction = directions.next().unwrap();
+            match direction {
+                Direction::Left => {
+                    current = map.get(&current).unwrap().left;
+                }
+                Direction::Right => {
+                    current = map.get(&current).unwrap().right;
+                }
+            }
+        }
+        lcm = lcm.lcm(&step);
+    }
+
+    lcm
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Parsed<'i> = Network<'i>;
+
+    fn parse(input: &str) -> Network<'_> {
+        parse_network(input)
+    }
+
+    fn part_one(network: &Network<'_>) -> Answer {
+        part_one_work(network).into()
+    }
+
+    fn part_two(network: &Network<'_>) -> Answer {
+        part_two_work(network).into()
+    }
+}
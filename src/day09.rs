@@ -0,0 +1,84 @@
+//! Day 9: Mirage Maintenance
+#![allow(clippy::missing_panics_doc)]
+
+use crate::{Answer, Solution};
+
+pub type Int = i32;
+
+#[must_use]
+pub fn difference(sequence: &[Int]) -> Vec<Int> {
+    sequence.windows(2).map(|pair| pair[1] - pair[0]).collect()
+}
+
+#[must_use]
+pub fn sequences(input: &str) -> Vec<Vec<Int>> {
+    input
+        .lines()
+        .map(|line| {
+            line.split(' ')
+                .map(|number| number.parse().unwrap())
+                .collect::<Vec<Int>>()
+        })
+        .collect()
+}
+
+#[must_use]
+pub fn pyramid(sequence: &[Int]) -> Vec<Vec<Int>> {
+    let mut pyramid = vec![sequence.to_vec()];
+    loop {
+        let next = difference(pyramid.last().unwrap());
+        if next.iter().all(|&d| d == 0) {
+            break;
+        }
+        pyramid.push(next);
+    }
+    pyramid
+}
+
+#[must_use]
+pub fn part_one_work(sequences: &[Vec<Int>]) -> Int {
+    let mut sum = 0;
+    for sequence in sequences {
+        let pyramid = pyramid(sequence);
+        let mut prev_diff = 0;
+        for sequence in pyramid.into_iter().rev() {
+            prev_diff += *sequence.last().unwrap();
+        }
+        sum += prev_diff;
+    }
+
+    sum
+}
+
+#[must_use]
+pub fn part_two_work(sequences: &[Vec<Int>]) -> Int {
+    let mut sum = 0;
+    for sequence in sequences {
+        let pyramid = pyramid(sequence);
+        let mut prev_diff = 0;
+        for sequence in pyramid.into_iter().rev() {
+            prev_diff = *sequence.first().unwrap() - prev_diff;
+        }
+        sum += prev_diff;
+    }
+
+    sum
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Parsed<'i> = Vec<Vec<Int>>;
+
+    fn parse(input: &str) -> Vec<Vec<Int>> {
+        sequences(input)
+    }
+
+    fn part_one(sequences: &Vec<Vec<Int>>) -> Answer {
+        part_one_work(sequences).into()
+    }
+
+    fn part_two(sequences: &Vec<Vec<Int>>) -> Answer {
+        part_two_work(sequences).into()
+    }
+}
@@ -0,0 +1,291 @@
+//! Day 11: Cosmic Expansion
+#![allow(clippy::missing_panics_doc)]
+
+use crate::{Answer, Part, Solution, Variant};
+use itertools::Itertools;
+use ndarray::{Array2, ArrayView};
+use pathfinding::prelude::astar;
+use std::collections::{HashMap, HashSet};
+use std::fmt::{Debug, Display, Formatter, Write};
+use std::str::FromStr;
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Default)]
+pub enum GridCell {
+    #[default]
+    Empty,
+    DoubleEmpty,
+    Galaxy,
+}
+
+impl GridCell {
+    fn to_char(self) -> char {
+        match self {
+            GridCell::Empty => '⋅',
+            GridCell::DoubleEmpty => '⋄',
+            GridCell::Galaxy => '⋇',
+        }
+    }
+}
+
+type GalaxyId = usize;
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+struct GalaxyIdPair {
+    lower: GalaxyId,
+    higher: GalaxyId,
+}
+
+impl GalaxyIdPair {
+    fn new(id_1: GalaxyId, id_2: GalaxyId) -> Self {
+        Self {
+            lower: id_1.min(id_2),
+            higher: id_1.max(id_2),
+        }
+    }
+}
+
+impl TryFrom<char> for GridCell {
+    type Error = ();
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        let gc = match value {
+            '.' => GridCell::Empty,
+            '#' => GridCell::Galaxy,
+            _ => {
+                return Err(());
+            }
+        };
+        Ok(gc)
+    }
+}
+
+pub struct Grid {
+    array: Array2<GridCell>,
+}
+
+impl Display for Grid {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for row in self.array.rows() {
+            for gc in row {
+                f.write_char(gc.to_char())?;
+            }
+            f.write_char('\n')?;
+        }
+        Ok(())
+    }
+}
+
+impl Grid {
+    fn galaxy_idx(&self) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.array
+            .rows()
+            .into_iter()
+            .enumerate()
+            .flat_map(|(row_idx, row)| {
+                row.into_iter()
+                    .enumerate()
+                    .filter_map(move |(col_idx, gc)| {
+                        if let GridCell::Galaxy = gc {
+                            Some((row_idx, col_idx))
+                        } else {
+                            None
+                        }
+                    })
+            })
+    }
+    fn galaxy_id_idx(&self) -> HashMap<GalaxyId, (usize, usize)> {
+        self.galaxy_idx().enumerate().collect()
+    }
+    /// The sum of the Manhattan distances between all pairs of galaxies, where crossing an
+    /// expanded row or column costs `double_cost`.
+    fn manhattan_distances(&self, double_cost: u32) -> u32 {
+        let expanded = |lanes: ndarray::iter::Lanes<'_, GridCell, _>| -> Vec<bool> {
+            lanes
+                .into_iter()
+                .map(|lane| lane.iter().all(|&gc| gc == GridCell::DoubleEmpty))
+                .collect()
+        };
+        let rows = expanded(self.array.rows());
+        let cols = expanded(self.array.columns());
+        let span = |a: usize, b: usize, expanded: &[bool]| -> u32 {
+            expanded[a.min(b)..a.max(b)]
+                .iter()
+                .map(|&expanded| if expanded { double_cost } else { 1 })
+                .sum()
+        };
+        self.galaxy_idx()
+            .collect_vec()
+            .into_iter()
+            .tuple_combinations()
+            .map(|((row_1, col_1), (row_2, col_2))| {
+                span(row_1, row_2, &rows) + span(col_1, col_2, &cols)
+            })
+            .sum()
+    }
+}
+
+impl FromStr for Grid {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let cols = s.lines().next().unwrap().len();
+        let mut array = Array2::default((0, cols));
+
+        for line in s.lines() {
+            array
+                .push_row(ArrayView::from(
+                    &line
+                        .chars()
+                        .map(|c| GridCell::try_from(c).unwrap())
+                        .collect::<Vec<_>>(),
+                ))
+                .unwrap();
+        }
+
+        let empty_cols: Vec<_> = array
+            .columns()
+            .into_iter()
+            .enumerate()
+            .filter_map(|(col_idx, col)| {
+                col.iter()
+                    .all(|&gc| gc == GridCell::Empty)
+                    .then_some(col_idx)
+            })
+            .collect();
+
+        let empty_rows: Vec<_> = array
+            .rows()
+            .into_iter()
+            .enumerate()
+            .filter_map(|(row_idx, row)| {
+                row.iter()
+                    .all(|&gc| gc == GridCell::Empty)
+                    .then_some(row_idx)
+            })
+            .collect();
+
+        for col in empty_cols {
+            for gc in array.column_mut(col) {
+                *gc = GridCell::DoubleEmpty;
+            }
+        }
+
+        for row in empty_rows {
+            for gc in array.row_mut(row) {
+                *gc = GridCell::DoubleEmpty;
+            }
+        }
+
+        Ok(Self { array })
+    }
+}
+
+fn shortest_path_length(
+    start: (usize, usize),
+    goal: (usize, usize),
+    grid: &Grid,
+    double_cost: u32,
+) -> u32 {
+    let (_, length) = astar(
+        &start,
+        |&idx| {
+            // Paths only step to the horizontally or vertically adjacent cells.
+            const STEPS: [(isize, isize); 4] = [(-1, 0), (1, 0), (0, -1), (0, 1)];
+            fn add(
+                (row, col): (usize, usize),
+                (row_d, col_d): (isize, isize),
+            ) -> Option<(usize, usize)> {
+                fn add(a: usize, r: isize) -> Option<usize> {
+                    usize::try_from(isize::try_from(a).unwrap() + r).ok()
+                }
+                Some((add(row, row_d)?, add(col, col_d)?))
+            }
+            STEPS.into_iter().filter_map(move |d| {
+                let new_idx = add(idx, d)?;
+                let gc = grid.array.get(new_idx)?;
+                let cost = if let GridCell::DoubleEmpty = gc {
+                    double_cost
+                } else {
+                    1
+                };
+                Some((new_idx, cost))
+            })
+        },
+        |_| 0,
+        |&current| current == goal,
+    )
+    .unwrap();
+    length
+}
+
+#[must_use]
+pub fn part_one_work(grid: &Grid) -> u32 {
+    let galaxy_id_idx = grid.galaxy_id_idx();
+    let galaxy_ids = galaxy_id_idx.keys().copied();
+    let pairs: HashSet<_> = galaxy_ids
+        .clone()
+        .cartesian_product(galaxy_ids)
+        .map(|(id_1, id_2)| GalaxyIdPair::new(id_1, id_2))
+        .collect();
+
+    pairs
+        .into_iter()
+        .map(
+            |GalaxyIdPair {
+                 lower: a,
+                 higher: b,
+             }| {
+                let a = *galaxy_id_idx.get(&a).unwrap();
+                let b = *galaxy_id_idx.get(&b).unwrap();
+                shortest_path_length(a, b, grid, 2)
+            },
+        )
+        .sum()
+}
+
+/// Sums the shortest paths like [`part_one_work`], but without searching for them.
+#[must_use]
+pub fn part_one_manhattan(grid: &Grid) -> u32 {
+    grid.manhattan_distances(2)
+}
+
+#[must_use]
+pub fn part_two_work(_grid: &Grid) -> u32 {
+    todo!()
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Parsed<'i> = Grid;
+
+    const VARIANTS: &'static [Variant<Self>] = &[Variant {
+        part: Part::One,
+        name: "manhattan",
+        solve: |grid| part_one_manhattan(grid).into(),
+    }];
+
+    fn parse(input: &str) -> Grid {
+        input.parse().unwrap()
+    }
+
+    fn part_one(grid: &Grid) -> Answer {
+        part_one_work(grid).into()
+    }
+
+    fn part_two(grid: &Grid) -> Answer {
+        part_two_work(grid).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{shortest_path_length, Grid};
+
+    #[test]
+    fn paths_do_not_step_diagonally() {
+        let grid: Grid = ".#\n#.".parse().unwrap();
+        // Two steps, where a diagonal step like (1, -1) would take one.
+        assert_eq!(shortest_path_length((0, 1), (1, 0), &grid, 2), 2);
+        assert_eq!(shortest_path_length((1, 0), (0, 1), &grid, 2), 2);
+    }
+}
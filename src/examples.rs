@@ -0,0 +1,209 @@
+//! The puzzles' example inputs with their expected answers, kept in `examples/NN/` as a
+//! `<name>.txt` input next to a `<name>.toml` with the expected `part_one` and `part_two`. Either
+//! may be left out or empty while it is not known.
+
+use crate::{io_error, read_file, Answer, AocError, Args, Entry, Failure, Part};
+use serde::Deserialize;
+use std::{
+    fmt::{self, Display, Formatter},
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+/// Directory holding a subdirectory of examples per day.
+pub const DEFAULT_EXAMPLES_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/examples");
+
+/// The expected answers to an example.
+#[derive(Debug, Default, Clone, Eq, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Expected {
+    pub part_one: Option<String>,
+    pub part_two: Option<String>,
+}
+
+impl Expected {
+    /// The expected answer to `part`, unless it is left out or empty.
+    #[must_use]
+    pub fn get(&self, part: Part) -> Option<&str> {
+        match part {
+            Part::One => self.part_one.as_deref(),
+            Part::Two => self.part_two.as_deref(),
+        }
+        .filter(|answer| !answer.is_empty())
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Example {
+    /// The file stem, e.g. `1` for `1.txt`.
+    pub name: String,
+    pub path: PathBuf,
+    /// The input, [`normalize`](crate::normalize)d.
+    pub input: String,
+    pub expected: Expected,
+}
+
+/// The directory with the examples of `day` in `dir`.
+#[must_use]
+pub fn day_dir(dir: &Path, day: u8) -> PathBuf {
+    dir.join(format!("{day:02}"))
+}
+
+/// Loads the examples of `day` from [`day_dir`], sorted by name, or none if it does not exist.
+///
+/// # Errors
+///
+/// If an example cannot be read or its expected answers are missing or invalid.
+pub fn load(dir: &Path, day: u8) -> Result<Vec<Example>, AocError> {
+    let dir = day_dir(dir, day);
+    let entries = match fs::read_dir(&dir) {
+        Ok(entries) => entries,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => return Err(io_error(&dir)(e)),
+    };
+    let mut paths = Vec::new();
+    for entry in entries {
+        let path = entry.map_err(io_error(&dir))?.path();
+        if path.extension().is_some_and(|extension| extension == "txt") {
+            paths.push(path);
+        }
+    }
+    paths.sort();
+    paths
+        .into_iter()
+        .map(|path| {
+            let expected_path = path.with_extension("toml");
+            let expected =
+                toml::from_str(&read_file(&expected_path)?).map_err(|e| AocError::InvalidToml {
+                    path: expected_path,
+                    message: e.message().to_string(),
+                })?;
+            Ok(Example {
+                name: path
+                    .file_stem()
+                    .unwrap_or_default()
+                    .to_string_lossy()
+                    .into_owned(),
+                input: crate::normalize(&read_file(&path)?),
+                path,
+                expected,
+            })
+        })
+        .collect()
+}
+
+/// The answer of a solver to an example, see [`check`].
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Checked {
+    pub part: Part,
+    /// The [`Variant`](crate::Variant) that gave the answer, if not the main solver.
+    pub variant: Option<&'static str>,
+    pub expected: String,
+    pub answer: Result<Answer, Failure>,
+}
+
+impl Checked {
+    #[must_use]
+    pub fn passed(&self) -> bool {
+        self.answer
+            .as_ref()
+            .is_ok_and(|answer| answer.to_string() == self.expected)
+    }
+
+    /// Whether the part is not implemented yet, which the runner does not count as a failure
+    /// either.
+    #[must_use]
+    pub fn pending(&self) -> bool {
+        self.answer == Err(Failure::Unimplemented)
+    }
+}
+
+impl Display for Checked {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.part)?;
+        if let Some(variant) = self.variant {
+            write!(f, " ({variant})")?;
+        }
+        match &self.answer {
+            Ok(_) if self.passed() => write!(f, " {} ok", self.expected),
+            Ok(answer) => write!(f, " {answer}, expected {}", self.expected),
+            Err(failure) => write!(f, " {failure}, expected {}", self.expected),
+        }
+    }
+}
+
+/// Runs the solvers of `entry`, variants included, on `example` for each part it has an expected
+/// answer to.
+#[must_use]
+pub fn check(entry: &Entry, example: &Example) -> Vec<Checked> {
+    let mut checked = Vec::new();
+    for part in Part::ALL {
+        let Some(expected) = example.expected.get(part) else {
+            continue;
+        };
+        let args = Args {
+            part: Some(part),
+            variants: true,
+            ..Args::default()
+        };
+        entry.solve(&example.input, &args, &mut |part, variant, answer| {
+            checked.push(Checked {
+                part,
+                variant,
+                expected: expected.to_string(),
+                answer: answer.map(|(answer, ..)| answer),
+            });
+        });
+    }
+    checked
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{check, load, Expected};
+    use crate::{AocError, Part, DAYS};
+    use std::fs;
+
+    #[test]
+    fn loads_and_checks_examples() {
+        let dir = std::env::temp_dir().join(format!("aoc-examples-test-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        assert!(load(&dir, 9).unwrap().is_empty());
+        fs::create_dir_all(dir.join("09")).unwrap();
+        fs::write(dir.join("09/b.txt"), "10 13 16 21 30 45\n").unwrap();
+        fs::write(
+            dir.join("09/b.toml"),
+            "part_one = \"68\"\npart_two = \"\"\n",
+        )
+        .unwrap();
+        fs::write(dir.join("09/a.txt"), "0 3 6 9 12 15\n").unwrap();
+        fs::write(
+            dir.join("09/a.toml"),
+            "part_one = \"18\"\npart_two = \"-4\"\n",
+        )
+        .unwrap();
+
+        let examples = load(&dir, 9).unwrap();
+        assert_eq!(examples[0].name, "a");
+        assert_eq!(examples[1].input, "10 13 16 21 30 45");
+        assert_eq!(
+            examples[1].expected,
+            Expected {
+                part_one: Some("68".to_string()),
+                part_two: Some(String::new()),
+            }
+        );
+        assert_eq!(examples[1].expected.get(Part::Two), None);
+
+        let day = DAYS.iter().find(|entry| entry.day == 9).unwrap();
+        let checked = check(day, &examples[0]);
+        assert_eq!(checked.len(), 2);
+        assert!(!checked[1].passed());
+        assert_eq!(checked[1].to_string(), "part two -3, expected -4");
+        assert_eq!(checked[0].to_string(), "part one 18 ok");
+
+        fs::remove_file(dir.join("09/b.toml")).unwrap();
+        assert!(matches!(load(&dir, 9), Err(AocError::Io { .. })));
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -1,12 +1,1564 @@
-use aoc_cache::get;
-use std::path::Path;
+pub mod alloc_stats;
+pub mod answers;
+pub mod bench;
+pub mod examples;
+pub mod scaffold;
+pub mod submit;
+
+// Like the binaries they started out in, the days panic on malformed input, so each allows
+// `clippy::missing_panics_doc`.
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
+
+use alloc_stats::AllocStats;
+use bench::{Phase, Stats};
+use serde::{de::DeserializeOwned, Serialize};
+use sha2::{Digest, Sha256};
+use std::{
+    any::Any,
+    cell::Cell,
+    env,
+    error::Error,
+    fmt::{self, Display, Formatter, Write},
+    fs, hint,
+    io::{self, Read},
+    iter,
+    panic::{self, AssertUnwindSafe},
+    path::{Path, PathBuf},
+    str::FromStr,
+    sync::{mpsc, Arc, Mutex, Once, OnceLock, PoisonError},
+    thread,
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
+};
 use winnow::{
     error::{ErrorKind, ParserError},
     token::any,
     PResult, Parser,
 };
 
-pub const MY_COOKIE: &str = include_str!("my.cookie");
+/// The event this crate solves. Change it when reusing the scaffolding for another year.
+pub const YEAR: u16 = 2023;
+/// The first Advent of Code event.
+pub const FIRST_YEAR: u16 = 2015;
+/// Environment variable naming an input file to use instead of the default sources, or `-` for
+/// stdin.
+pub const INPUT_ENV: &str = "AOC_INPUT";
+/// Environment variable overriding the local input directory.
+pub const INPUT_DIR_ENV: &str = "AOC_INPUT_DIR";
+/// Local input directory used when [`INPUT_DIR_ENV`] is not set.
+pub const DEFAULT_INPUT_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/inputs");
+/// Environment variable holding the session cookie, either as `session=...` or just the token.
+pub const SESSION_ENV: &str = "AOC_SESSION";
+/// Environment variable naming a file that holds the session cookie.
+pub const COOKIE_FILE_ENV: &str = "AOC_COOKIE_FILE";
+/// Environment variable overriding the directory of [`InputCache::default_location`].
+pub const CACHE_DIR_ENV: &str = "AOC_CACHE_DIR";
+/// Environment variable overriding [`DEFAULT_USER_AGENT`], e.g. to add your contact details like
+/// `github.com/glennib/aoc-2023 by you@example.com`.
+pub const USER_AGENT_ENV: &str = "AOC_USER_AGENT";
+/// Identifies this tool to adventofcode.com, as the site asks automated tools to. It has no contact
+/// details; those go in [`USER_AGENT_ENV`].
+pub const DEFAULT_USER_AGENT: &str = "github.com/glennib/aoc-2023";
+/// Where [`Client`] sends requests unless told otherwise.
+pub const BASE_URL: &str = "https://adventofcode.com";
+
+/// Everything that can go wrong while getting puzzle input or checking answers.
+#[derive(Debug)]
+pub enum AocError {
+    /// The input had to be downloaded, but no session [`cookie`] was found.
+    MissingCookie,
+    /// adventofcode.com answered with a non-success status code.
+    HttpStatus { url: String, status: u16 },
+    /// adventofcode.com answered with an error message instead of the input, e.g. because the
+    /// cookie expired. `cached` is set when the message was found in the [`InputCache`].
+    ErrorPage {
+        puzzle: Puzzle,
+        message: String,
+        cached: bool,
+    },
+    /// adventofcode.com could not be reached.
+    Transport(String),
+    /// adventofcode.com answered a submission with a page that does not say how it went, e.g.
+    /// because the cookie expired.
+    UnrecognizedResponse { url: String, text: String },
+    /// The puzzle has not unlocked yet.
+    Locked { puzzle: Puzzle, remaining: Duration },
+    /// A file name or argument does not name a puzzle day between 1 and 25.
+    InvalidDayNumber(String),
+    /// A year before the first Advent of Code, [`FIRST_YEAR`].
+    InvalidYear(u16),
+    /// A command-line argument was not understood.
+    InvalidArgument(String),
+    /// No input source had the input.
+    NoInput { puzzle: Puzzle },
+    /// Reading or writing a file failed; `path` is `None` for stdin.
+    Io {
+        path: Option<PathBuf>,
+        source: io::Error,
+    },
+    /// The [`InputCache`] index has a malformed line.
+    CorruptCache { index: PathBuf, line: String },
+    /// A TOML file, i.e. the known [`answers`] or the [`bench`] baseline, is not valid.
+    InvalidToml { path: PathBuf, message: String },
+    /// [`scaffold::new_day`] would overwrite existing work.
+    AlreadyExists { path: PathBuf },
+    /// Watching files for changes failed.
+    Watch(String),
+    /// A file to rewrite a section of, e.g. the results in `README.md`, lacks its markers.
+    MissingMarkers {
+        path: PathBuf,
+        start: &'static str,
+        end: &'static str,
+    },
+}
+
+impl Display for AocError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            AocError::MissingCookie => write!(
+                f,
+                "no session cookie found, set {SESSION_ENV} or see `aoc_2023::cookie`"
+            ),
+            AocError::HttpStatus { url, status } => write!(f, "{url} returned status {status}"),
+            AocError::ErrorPage {
+                puzzle,
+                message,
+                cached,
+            } => {
+                write!(
+                    f,
+                    "got an error page instead of input for {puzzle}: {message}"
+                )?;
+                if *cached {
+                    write!(f, " (cached, remove it with `purge_cached_input`)")?;
+                }
+                Ok(())
+            }
+            AocError::Transport(e) => write!(f, "cannot reach adventofcode.com: {e}"),
+            AocError::UnrecognizedResponse { url, text } => {
+                write!(f, "{url} answered with an unrecognized page: {text}")
+            }
+            AocError::Locked { puzzle, remaining } => {
+                let secs = remaining.as_secs();
+                let (days, hours, minutes, seconds) =
+                    (secs / 86_400, secs / 3600 % 24, secs / 60 % 60, secs % 60);
+                write!(f, "{puzzle} unlocks in ")?;
+                if days > 0 {
+                    write!(f, "{days}d ")?;
+                }
+                write!(f, "{hours:02}h {minutes:02}m {seconds:02}s")
+            }
+            AocError::InvalidDayNumber(s) => write!(f, "`{s}` is not a day between 1 and 25"),
+            AocError::InvalidYear(year) => write!(
+                f,
+                "{year} has no Advent of Code, the first was in {FIRST_YEAR}"
+            ),
+            AocError::InvalidArgument(arg) => write!(f, "invalid argument: {arg}"),
+            AocError::NoInput { puzzle } => write!(f, "no input found for {puzzle}"),
+            AocError::Io {
+                path: Some(path),
+                source,
+            } => write!(f, "cannot access {}: {source}", path.display()),
+            AocError::Io { path: None, source } => write!(f, "cannot read stdin: {source}"),
+            AocError::CorruptCache { index, line } => {
+                write!(f, "malformed line `{line}` in {}", index.display())
+            }
+            AocError::InvalidToml { path, message } => {
+                write!(f, "invalid TOML in {}: {message}", path.display())
+            }
+            AocError::AlreadyExists { path } => {
+                write!(f, "{} already exists, not overwriting it", path.display())
+            }
+            AocError::Watch(e) => write!(f, "cannot watch for changes: {e}"),
+            AocError::MissingMarkers { path, start, end } => write!(
+                f,
+                "{} has no section between `{start}` and `{end}`",
+                path.display()
+            ),
+        }
+    }
+}
+
+impl Error for AocError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            AocError::Io { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+impl From<ureq::Error> for AocError {
+    fn from(e: ureq::Error) -> Self {
+        match e {
+            ureq::Error::Status(status, response) => AocError::HttpStatus {
+                url: response.get_url().to_string(),
+                status,
+            },
+            ureq::Error::Transport(e) => AocError::Transport(e.to_string()),
+        }
+    }
+}
+
+fn io_error(path: &Path) -> impl FnOnce(io::Error) -> AocError + '_ {
+    |source| AocError::Io {
+        path: Some(path.to_path_buf()),
+        source,
+    }
+}
+
+fn read_file(path: &Path) -> Result<String, AocError> {
+    fs::read_to_string(path).map_err(io_error(path))
+}
+
+fn write_file(path: &Path, contents: &str) -> Result<(), AocError> {
+    fs::write(path, contents).map_err(io_error(path))
+}
+
+/// Reads a TOML file, which is empty if it does not exist yet.
+fn read_toml<T: DeserializeOwned + Default>(path: &Path) -> Result<T, AocError> {
+    match fs::read_to_string(path) {
+        Ok(contents) => toml::from_str(&contents).map_err(|e| AocError::InvalidToml {
+            path: path.to_path_buf(),
+            message: e.message().to_string(),
+        }),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(T::default()),
+        Err(e) => Err(io_error(path)(e)),
+    }
+}
+
+fn write_toml(path: &Path, header: &str, value: &impl Serialize) -> Result<(), AocError> {
+    let toml = toml::to_string(value).expect("maps of plain structs serialize to TOML");
+    write_file(path, &format!("{header}{toml}"))
+}
+
+/// The TOML table holding a day's entries, e.g. `day07`.
+fn day_key(day: u8) -> String {
+    format!("day{day:02}")
+}
+
+/// Finds the adventofcode.com session cookie, trying in order:
+///
+/// 1. The [`SESSION_ENV`] environment variable.
+/// 2. The file named by [`COOKIE_FILE_ENV`].
+/// 3. `aoc/cookie` in `$XDG_CONFIG_HOME`, defaulting to `$HOME/.config`.
+///
+/// # Errors
+///
+/// If [`COOKIE_FILE_ENV`] is set but the file cannot be read.
+pub fn cookie() -> Result<Option<String>, AocError> {
+    if let Some(session) = env::var_os(SESSION_ENV) {
+        return Ok(normalize_cookie(&session.to_string_lossy()));
+    }
+    if let Some(path) = env::var_os(COOKIE_FILE_ENV) {
+        return Ok(normalize_cookie(&read_file(Path::new(&path))?));
+    }
+    let Some(config_dir) = env::var_os("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .or_else(|| env::var_os("HOME").map(|home| Path::new(&home).join(".config")))
+    else {
+        return Ok(None);
+    };
+    Ok(fs::read_to_string(config_dir.join("aoc").join("cookie"))
+        .ok()
+        .and_then(|cookie| normalize_cookie(&cookie)))
+}
+
+fn normalize_cookie(cookie: &str) -> Option<String> {
+    let cookie = cookie.trim();
+    if cookie.is_empty() {
+        None
+    } else if cookie.starts_with("session=") {
+        Some(cookie.to_string())
+    } else {
+        Some(format!("session={cookie}"))
+    }
+}
+
+/// Identifies a puzzle by event year and day. The year is always [`FIRST_YEAR`] or later and the
+/// day between 1 and 25, as checked by [`Puzzle::new`].
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct Puzzle {
+    year: u16,
+    day: u8,
+}
+
+impl Puzzle {
+    /// # Errors
+    ///
+    /// If `year` is before [`FIRST_YEAR`] or `day` is not between 1 and 25.
+    pub fn new(year: u16, day: u8) -> Result<Self, AocError> {
+        if year < FIRST_YEAR {
+            Err(AocError::InvalidYear(year))
+        } else if (1..=25).contains(&day) {
+            Ok(Self { year, day })
+        } else {
+            Err(AocError::InvalidDayNumber(day.to_string()))
+        }
+    }
+
+    #[must_use]
+    pub fn year(self) -> u16 {
+        self.year
+    }
+
+    #[must_use]
+    pub fn day(self) -> u8 {
+        self.day
+    }
+
+    /// The [`YEAR`] puzzle solved by a binary, e.g. day 7 for `src/bin/07.rs`.
+    ///
+    /// # Errors
+    ///
+    /// If the file stem is not a day between 1 and 25.
+    pub fn try_from_file(file: &str) -> Result<Self, AocError> {
+        let day = parse_day(try_day_number(file)?)?;
+        Self::new(YEAR, day)
+    }
+
+    /// Panicking version of [`Puzzle::try_from_file`].
+    ///
+    /// # Panics
+    ///
+    /// If the file stem is not a day between 1 and 25.
+    #[must_use]
+    pub fn from_file(file: &str) -> Self {
+        Self::try_from_file(file).unwrap_or_else(|e| panic!("{e}"))
+    }
+
+    /// Puzzles unlock at midnight US-Eastern (UTC-5) on their day of December.
+    #[must_use]
+    pub fn unlocks_at(self) -> SystemTime {
+        let days = days_since_epoch(i64::from(self.year), 12, i64::from(self.day));
+        let secs = days * 86_400 + 5 * 3600;
+        // Years are from 2015 on, so this is always after the epoch.
+        UNIX_EPOCH + Duration::from_secs(secs.try_into().unwrap_or_default())
+    }
+
+    /// The path of the input below [`BASE_URL`].
+    #[must_use]
+    pub fn input_path(self) -> String {
+        format!("/{}/day/{}/input", self.year, self.day)
+    }
+
+    /// The path below [`BASE_URL`] that answers are posted to.
+    #[must_use]
+    pub fn answer_path(self) -> String {
+        format!("/{}/day/{}/answer", self.year, self.day)
+    }
+
+    #[must_use]
+    pub fn input_url(self) -> String {
+        format!("{BASE_URL}{}", self.input_path())
+    }
+
+    /// The inverse of [`Puzzle::input_url`].
+    #[must_use]
+    pub fn from_input_url(url: &str) -> Option<Self> {
+        let (year, rest) = url
+            .strip_prefix(BASE_URL)?
+            .strip_prefix('/')?
+            .split_once('/')?;
+        let day = rest.strip_prefix("day/")?.strip_suffix("/input")?;
+        Self::new(year.parse().ok()?, day.parse().ok()?).ok()
+    }
+}
+
+/// Parses `7` as day 7 of [`YEAR`] and `2022/7` as day 7 of 2022.
+impl FromStr for Puzzle {
+    type Err = AocError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || AocError::InvalidDayNumber(s.to_string());
+        let (year, day) = match s.split_once('/') {
+            Some((year, day)) => (year.parse().map_err(|_| invalid())?, day),
+            None => (YEAR, s),
+        };
+        Self::new(year, parse_day(day).map_err(|_| invalid())?)
+    }
+}
+
+impl Display for Puzzle {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{} day {}", self.year, self.day)
+    }
+}
+
+/// Days from 1970-01-01 to the given proleptic Gregorian date.
+fn days_since_epoch(year: i64, month: i64, day: i64) -> i64 {
+    // http://howardhinnant.github.io/date_algorithms.html#days_from_civil
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * (month + if month > 2 { -3 } else { 9 }) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+/// A source of the current time, so the unlock guard can be tested.
+pub trait Clock {
+    fn now(&self) -> SystemTime;
+}
+
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> SystemTime {
+        SystemTime::now()
+    }
+}
+
+/// Fails with [`AocError::Locked`] if `puzzle` has not unlocked according to `clock`.
+///
+/// # Errors
+///
+/// If `puzzle` has not unlocked yet.
+pub fn ensure_unlocked(puzzle: Puzzle, clock: &impl Clock) -> Result<(), AocError> {
+    match puzzle.unlocks_at().duration_since(clock.now()) {
+        Ok(remaining) if !remaining.is_zero() => Err(AocError::Locked { puzzle, remaining }),
+        _ => Ok(()),
+    }
+}
+
+/// Somewhere puzzle input can be read from.
+pub trait InputSource {
+    /// Returns the input for `puzzle`, or `None` if this source doesn't have it.
+    ///
+    /// # Errors
+    ///
+    /// If the source has the input but cannot read it.
+    fn get(&self, puzzle: Puzzle) -> Result<Option<String>, AocError>;
+}
+
+/// A directory of `NN.txt` files, e.g. `inputs/07.txt`. Puzzles from years other than [`YEAR`]
+/// live in a subdirectory per year, e.g. `inputs/2022/07.txt`.
+pub struct InputDir {
+    dir: PathBuf,
+}
+
+impl InputDir {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self { dir: dir.into() }
+    }
+
+    /// The directory named by [`INPUT_DIR_ENV`], defaulting to [`DEFAULT_INPUT_DIR`].
+    #[must_use]
+    pub fn default_location() -> Self {
+        Self::new(
+            env::var_os(INPUT_DIR_ENV)
+                .map_or_else(|| PathBuf::from(DEFAULT_INPUT_DIR), PathBuf::from),
+        )
+    }
+
+    #[must_use]
+    pub fn path(&self, puzzle: Puzzle) -> PathBuf {
+        let file_name = format!("{:02}.txt", puzzle.day);
+        if puzzle.year == YEAR {
+            self.dir.join(file_name)
+        } else {
+            self.dir.join(puzzle.year.to_string()).join(file_name)
+        }
+    }
+}
+
+impl InputSource for InputDir {
+    fn get(&self, puzzle: Puzzle) -> Result<Option<String>, AocError> {
+        let path = self.path(puzzle);
+        match read_file(&path) {
+            Ok(input) => Ok(Some(input)),
+            Err(AocError::Io { source, .. }) if source.kind() == io::ErrorKind::NotFound => {
+                Ok(None)
+            }
+            Err(e) => Err(e),
+        }
+    }
+}
+
+/// A single file used as input regardless of the day.
+pub struct InputFile {
+    path: PathBuf,
+}
+
+impl InputFile {
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self { path: path.into() }
+    }
+}
+
+impl InputSource for InputFile {
+    fn get(&self, _puzzle: Puzzle) -> Result<Option<String>, AocError> {
+        read_file(&self.path).map(Some)
+    }
+}
+
+/// Reads the whole of stdin regardless of the day.
+pub struct Stdin;
+
+impl InputSource for Stdin {
+    fn get(&self, _puzzle: Puzzle) -> Result<Option<String>, AocError> {
+        let mut input = String::new();
+        io::stdin()
+            .read_to_string(&mut input)
+            .map_err(|source| AocError::Io { path: None, source })?;
+        Ok(Some(input))
+    }
+}
+
+/// Phrases adventofcode.com answers with instead of puzzle input.
+const ERROR_PAGE_PHRASES: &[&str] = &[
+    "Please log in",
+    "Please don't repeatedly request this endpoint before it unlocks",
+    "404 Not Found",
+    "<!DOCTYPE html>",
+];
+
+/// Returns the first line of `input` if it is an adventofcode.com error message rather than
+/// puzzle input.
+fn error_page(input: &str) -> Option<&str> {
+    ERROR_PAGE_PHRASES
+        .iter()
+        .any(|phrase| input.contains(phrase))
+        .then(|| input.lines().next().unwrap_or_default().trim())
+}
+
+/// A polite HTTP client for adventofcode.com: it sends a User-Agent, keeps a minimum interval
+/// between requests and retries server errors with exponential backoff.
+///
+/// Clones share the interval, so all requests made through clones of [`Client::shared`] are
+/// spaced out, even across threads.
+#[derive(Clone)]
+pub struct Client {
+    base_url: String,
+    user_agent: String,
+    min_interval: Duration,
+    retries: u32,
+    backoff: Duration,
+    last_request: Arc<Mutex<Option<Instant>>>,
+}
+
+impl Client {
+    /// A client with its own request interval. The User-Agent is taken from [`USER_AGENT_ENV`],
+    /// defaulting to [`DEFAULT_USER_AGENT`].
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            base_url: BASE_URL.to_string(),
+            user_agent: env::var(USER_AGENT_ENV).unwrap_or_else(|_| DEFAULT_USER_AGENT.to_string()),
+            min_interval: Duration::from_secs(5),
+            retries: 3,
+            backoff: Duration::from_secs(1),
+            last_request: Arc::default(),
+        }
+    }
+
+    /// A clone of the process-wide client used by [`HttpCache::default`].
+    #[must_use]
+    pub fn shared() -> Self {
+        static SHARED: OnceLock<Client> = OnceLock::new();
+        SHARED.get_or_init(Client::new).clone()
+    }
+
+    #[must_use]
+    pub fn base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = base_url.into();
+        self
+    }
+
+    #[must_use]
+    pub fn user_agent(mut self, user_agent: impl Into<String>) -> Self {
+        self.user_agent = user_agent.into();
+        self
+    }
+
+    #[must_use]
+    pub fn min_interval(mut self, min_interval: Duration) -> Self {
+        self.min_interval = min_interval;
+        self
+    }
+
+    /// How many times a request answered with a 5xx status is retried.
+    #[must_use]
+    pub fn retries(mut self, retries: u32) -> Self {
+        self.retries = retries;
+        self
+    }
+
+    /// The wait before the first retry, doubling for every following one.
+    #[must_use]
+    pub fn backoff(mut self, backoff: Duration) -> Self {
+        self.backoff = backoff;
+        self
+    }
+
+    /// Sleeps until [`Client::min_interval`] has passed since the previous request.
+    fn throttle(&self) {
+        let mut last_request = self
+            .last_request
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        if let Some(wait) =
+            last_request.and_then(|last| self.min_interval.checked_sub(last.elapsed()))
+        {
+            thread::sleep(wait);
+        }
+        *last_request = Some(Instant::now());
+    }
+
+    /// Sends a GET request for `path`, retrying server errors. Returns the status and body of the
+    /// final response.
+    fn get(&self, path: &str, cookie: &str) -> Result<(u16, String), AocError> {
+        let agent = ureq::AgentBuilder::new()
+            .user_agent(&self.user_agent)
+            .build();
+        let url = format!("{}{path}", self.base_url);
+        let mut backoff = self.backoff;
+        let mut attempt = 0;
+        loop {
+            self.throttle();
+            let response = match agent.get(&url).set("Cookie", cookie).call() {
+                Ok(response) => response,
+                Err(ureq::Error::Status(status, response)) => {
+                    if (500..600).contains(&status) && attempt < self.retries {
+                        attempt += 1;
+                        thread::sleep(backoff);
+                        backoff *= 2;
+                        continue;
+                    }
+                    response
+                }
+                Err(e) => return Err(e.into()),
+            };
+            let status = response.status();
+            let body = response
+                .into_string()
+                .map_err(|e| AocError::Transport(e.to_string()))?;
+            return Ok((status, body));
+        }
+    }
+
+    /// Downloads the input for `puzzle`, refusing anything that looks like an [`error_page`].
+    ///
+    /// # Errors
+    ///
+    /// If the request fails, or if the server answers with an error status or page.
+    pub fn input(&self, puzzle: Puzzle, cookie: &str) -> Result<String, AocError> {
+        let path = puzzle.input_path();
+        let (status, body) = self.get(&path, cookie)?;
+        if let Some(message) = error_page(&body) {
+            return Err(AocError::ErrorPage {
+                puzzle,
+                message: message.to_string(),
+                cached: false,
+            });
+        }
+        if status >= 300 {
+            return Err(AocError::HttpStatus {
+                url: format!("{}{path}", self.base_url),
+                status,
+            });
+        }
+        Ok(body.trim_end().to_string())
+    }
+
+    /// Posts `answer` to `part` of `puzzle` and returns the [`Outcome`](submit::Outcome). Unlike
+    /// downloads, submissions are not retried, so that an answer is never sent twice.
+    ///
+    /// # Errors
+    ///
+    /// If the request fails, or if the server answers with an error status or a page that does
+    /// not tell the outcome.
+    pub fn submit(
+        &self,
+        puzzle: Puzzle,
+        part: Part,
+        answer: &Answer,
+        cookie: &str,
+    ) -> Result<submit::Outcome, AocError> {
+        let url = format!("{}{}", self.base_url, puzzle.answer_path());
+        self.throttle();
+        let response = ureq::AgentBuilder::new()
+            .user_agent(&self.user_agent)
+            .build()
+            .post(&url)
+            .set("Cookie", cookie)
+            .send_form(&[
+                ("level", &part.number().to_string()),
+                ("answer", &answer.to_string()),
+            ])?;
+        let page = response
+            .into_string()
+            .map_err(|e| AocError::Transport(e.to_string()))?;
+        submit::Outcome::parse(&page).ok_or_else(|| AocError::UnrecognizedResponse {
+            url,
+            text: article(&page).chars().take(200).collect(),
+        })
+    }
+}
+
+/// The text of the `<article>` of a page, which holds its message, or the whole page if it has
+/// none.
+fn article(page: &str) -> String {
+    let article = page
+        .split_once("<article")
+        .and_then(|(_, rest)| rest.split_once('>'))
+        .and_then(|(_, rest)| rest.split_once("</article>"))
+        .map_or(page, |(article, _)| article);
+    let mut text = String::new();
+    let mut in_tag = false;
+    for c in article.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+impl Default for Client {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The lowercase hex SHA-256 of `input`.
+#[must_use]
+pub fn input_hash(input: &str) -> String {
+    format!("{:x}", Sha256::digest(input))
+}
+
+/// A cached input, as listed by [`InputCache::entries`].
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct CacheEntry {
+    pub puzzle: Puzzle,
+    pub path: PathBuf,
+    /// `None` if the index lists a file that is missing.
+    pub size: Option<u64>,
+}
+
+/// Whether a cached input still matches the SHA-256 stored when it was downloaded.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum Integrity {
+    Intact,
+    Modified {
+        stored: String,
+        actual: String,
+    },
+    /// The input was cached without a hash, e.g. by the `aoc-cache` crate.
+    Unhashed,
+    /// The index lists the input, but its file is missing.
+    Missing,
+}
+
+/// Downloaded inputs on disk: an `index.cache` of `url: path` lines next to the cached files. This
+/// is the layout of the `aoc-cache` crate, so inputs it downloaded are still found. Inputs cached
+/// by [`InputCache::insert`] also get a `.sha256` file next to them.
+pub struct InputCache {
+    dir: PathBuf,
+}
+
+impl InputCache {
+    const INDEX_FILE_NAME: &'static str = "index.cache";
+
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self { dir: dir.into() }
+    }
+
+    /// The directory named by [`CACHE_DIR_ENV`], defaulting to the one `aoc-cache` used.
+    #[must_use]
+    pub fn default_location() -> Self {
+        Self::new(
+            env::var_os(CACHE_DIR_ENV).map_or_else(|| scratch::path("aoc_cache"), PathBuf::from),
+        )
+    }
+
+    fn index_path(&self) -> PathBuf {
+        self.dir.join(Self::INDEX_FILE_NAME)
+    }
+
+    fn index(&self) -> Result<Vec<(String, PathBuf)>, AocError> {
+        let index_path = self.index_path();
+        let index = match fs::read_to_string(&index_path) {
+            Ok(index) => index,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(e) => return Err(io_error(&index_path)(e)),
+        };
+        index
+            .lines()
+            .map(|line| {
+                line.split_once(": ")
+                    .map(|(url, path)| (url.to_string(), PathBuf::from(path)))
+                    .ok_or_else(|| AocError::CorruptCache {
+                        index: index_path.clone(),
+                        line: line.to_string(),
+                    })
+            })
+            .collect()
+    }
+
+    fn write_index(&self, index: &[(String, PathBuf)]) -> Result<(), AocError> {
+        let mut contents = String::new();
+        for (url, path) in index {
+            writeln!(contents, "{url}: {}", path.display())
+                .expect("writing to a String cannot fail");
+        }
+        write_file(&self.index_path(), &contents)
+    }
+
+    fn hash_path(path: &Path) -> PathBuf {
+        let mut hash_path = path.as_os_str().to_owned();
+        hash_path.push(".sha256");
+        PathBuf::from(hash_path)
+    }
+
+    fn path(&self, puzzle: Puzzle) -> Result<Option<PathBuf>, AocError> {
+        let url = puzzle.input_url();
+        Ok(self
+            .index()?
+            .into_iter()
+            .find_map(|(u, path)| (u == url).then_some(path)))
+    }
+
+    /// # Errors
+    ///
+    /// If the cache cannot be read, or if the cached input is an [`AocError::ErrorPage`].
+    pub fn get(&self, puzzle: Puzzle) -> Result<Option<String>, AocError> {
+        let Some(path) = self.path(puzzle)? else {
+            return Ok(None);
+        };
+        let input = read_file(&path)?;
+        if let Some(message) = error_page(&input) {
+            return Err(AocError::ErrorPage {
+                puzzle,
+                message: message.to_string(),
+                cached: true,
+            });
+        }
+        Ok(Some(input))
+    }
+
+    /// The cached file for `puzzle` as it is, even if it is an error page, e.g. to inspect it
+    /// before purging it.
+    ///
+    /// # Errors
+    ///
+    /// If the cache or the file cannot be read.
+    pub fn read(&self, puzzle: Puzzle) -> Result<Option<String>, AocError> {
+        self.path(puzzle)?.map(|path| read_file(&path)).transpose()
+    }
+
+    /// # Errors
+    ///
+    /// If the cache cannot be written.
+    pub fn insert(&self, puzzle: Puzzle, input: &str) -> Result<(), AocError> {
+        fs::create_dir_all(&self.dir).map_err(io_error(&self.dir))?;
+        let (path, indexed) = match self.path(puzzle)? {
+            Some(path) => (path, true),
+            None => (
+                self.dir
+                    .join(format!("input_{}_{:02}.cache", puzzle.year, puzzle.day)),
+                false,
+            ),
+        };
+        write_file(&path, input)?;
+        write_file(&Self::hash_path(&path), &input_hash(input))?;
+        if indexed {
+            return Ok(());
+        }
+        let mut index = self.index()?;
+        index.push((puzzle.input_url(), path));
+        self.write_index(&index)
+    }
+
+    /// Lists the cached puzzle inputs in order, including those whose file is missing. Index lines
+    /// for other URLs are skipped.
+    ///
+    /// # Errors
+    ///
+    /// If the cache cannot be read.
+    pub fn entries(&self) -> Result<Vec<CacheEntry>, AocError> {
+        let mut entries = self
+            .index()?
+            .into_iter()
+            .filter_map(|(url, path)| Some((Puzzle::from_input_url(&url)?, path)))
+            .map(|(puzzle, path)| {
+                let size = match fs::metadata(&path) {
+                    Ok(metadata) => Some(metadata.len()),
+                    Err(e) if e.kind() == io::ErrorKind::NotFound => None,
+                    Err(e) => return Err(io_error(&path)(e)),
+                };
+                Ok(CacheEntry { puzzle, path, size })
+            })
+            .collect::<Result<Vec<_>, AocError>>()?;
+        entries.sort_by_key(|entry| entry.puzzle);
+        Ok(entries)
+    }
+
+    /// Compares the cached input for `puzzle` with its stored hash, or returns `None` if it isn't
+    /// cached.
+    ///
+    /// # Errors
+    ///
+    /// If the cache cannot be read.
+    pub fn verify(&self, puzzle: Puzzle) -> Result<Option<Integrity>, AocError> {
+        let Some(path) = self.path(puzzle)? else {
+            return Ok(None);
+        };
+        let actual = match read_file(&path) {
+            Ok(input) => input_hash(&input),
+            Err(AocError::Io { source, .. }) if source.kind() == io::ErrorKind::NotFound => {
+                return Ok(Some(Integrity::Missing));
+            }
+            Err(e) => return Err(e),
+        };
+        let stored = match read_file(&Self::hash_path(&path)) {
+            Ok(stored) => stored.trim().to_string(),
+            Err(AocError::Io { source, .. }) if source.kind() == io::ErrorKind::NotFound => {
+                return Ok(Some(Integrity::Unhashed));
+            }
+            Err(e) => return Err(e),
+        };
+        Ok(Some(if stored == actual {
+            Integrity::Intact
+        } else {
+            Integrity::Modified { stored, actual }
+        }))
+    }
+
+    /// Removes the cached input for `puzzle`, returning whether there was one.
+    ///
+    /// # Errors
+    ///
+    /// If the cache cannot be read or written.
+    pub fn remove(&self, puzzle: Puzzle) -> Result<bool, AocError> {
+        let url = puzzle.input_url();
+        let mut index = self.index()?;
+        let Some(position) = index.iter().position(|(u, _)| *u == url) else {
+            return Ok(false);
+        };
+        let (_, path) = index.remove(position);
+        self.write_index(&index)?;
+        for path in [Self::hash_path(&path), path] {
+            match fs::remove_file(&path) {
+                Err(e) if e.kind() != io::ErrorKind::NotFound => return Err(io_error(&path)(e)),
+                _ => {}
+            }
+        }
+        Ok(true)
+    }
+}
+
+/// Removes a poisoned entry, e.g. a cached [`AocError::ErrorPage`], from the default
+/// [`InputCache`] so the next [`get_input`] downloads it again. Returns whether there was one.
+///
+/// # Errors
+///
+/// If the cache cannot be read or written.
+pub fn purge_cached_input(puzzle: Puzzle) -> Result<bool, AocError> {
+    InputCache::default_location().remove(puzzle)
+}
+
+/// Downloads from adventofcode.com with a [`Client`], keeping the result in an [`InputCache`]. The
+/// session [`cookie`] is only needed when the input isn't cached yet. Error pages are never
+/// cached, and puzzles that haven't unlocked according to the [`Clock`] are not requested.
+pub struct HttpCache<C = SystemClock> {
+    cache: InputCache,
+    client: Client,
+    cookie: Option<String>,
+    clock: C,
+}
+
+impl HttpCache {
+    #[must_use]
+    pub fn new(cache: InputCache) -> Self {
+        Self {
+            cache,
+            client: Client::shared(),
+            cookie: None,
+            clock: SystemClock,
+        }
+    }
+}
+
+impl<C> HttpCache<C> {
+    #[must_use]
+    pub fn with_client(mut self, client: Client) -> Self {
+        self.client = client;
+        self
+    }
+
+    /// Uses `cookie` instead of looking one up with [`cookie`].
+    #[must_use]
+    pub fn with_cookie(mut self, cookie: impl Into<String>) -> Self {
+        self.cookie = Some(cookie.into());
+        self
+    }
+
+    #[must_use]
+    pub fn with_clock<D: Clock>(self, clock: D) -> HttpCache<D> {
+        HttpCache {
+            cache: self.cache,
+            client: self.client,
+            cookie: self.cookie,
+            clock,
+        }
+    }
+}
+
+impl Default for HttpCache {
+    fn default() -> Self {
+        Self::new(InputCache::default_location())
+    }
+}
+
+impl<C: Clock> InputSource for HttpCache<C> {
+    fn get(&self, puzzle: Puzzle) -> Result<Option<String>, AocError> {
+        if let Some(input) = self.cache.get(puzzle)? {
+            return Ok(Some(input));
+        }
+        ensure_unlocked(puzzle, &self.clock)?;
+        let cookie = match &self.cookie {
+            Some(cookie) => cookie.clone(),
+            None => cookie()?.ok_or(AocError::MissingCookie)?,
+        };
+        let input = self.client.input(puzzle, &cookie)?;
+        self.cache.insert(puzzle, &input)?;
+        Ok(Some(input))
+    }
+}
+
+/// The sources [`get_input`] tries, in order:
+///
+/// 1. The file named by [`INPUT_ENV`], or stdin if it is `-`. When set, nothing else is tried.
+/// 2. `NN.txt` in the directory named by [`INPUT_DIR_ENV`], defaulting to [`DEFAULT_INPUT_DIR`].
+/// 3. [`HttpCache`].
+#[must_use]
+pub fn default_sources() -> Vec<Box<dyn InputSource>> {
+    match env::var_os(INPUT_ENV) {
+        Some(path) if path == "-" => vec![Box::new(Stdin)],
+        Some(path) => vec![Box::new(InputFile::new(path))],
+        None => vec![
+            Box::new(InputDir::default_location()),
+            Box::new(HttpCache::default()),
+        ],
+    }
+}
+
+/// One of the two parts of a puzzle.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+
+    /// 1 or 2, as adventofcode.com numbers the parts.
+    #[must_use]
+    pub fn number(self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Part::One => f.write_str("part one"),
+            Part::Two => f.write_str("part two"),
+        }
+    }
+}
+
+impl FromStr for Part {
+    type Err = AocError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "1" => Ok(Part::One),
+            "2" => Ok(Part::Two),
+            _ => Err(AocError::InvalidArgument(format!(
+                "`{s}` is not a part, expected 1 or 2"
+            ))),
+        }
+    }
+}
+
+/// Where [`Args`] reads the input from instead of [`default_sources`].
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum InputArg {
+    File(PathBuf),
+    Stdin,
+}
+
+/// Command-line arguments shared by the day binaries.
+#[derive(Debug, Default, Clone, Eq, PartialEq)]
+pub struct Args {
+    pub input: Option<InputArg>,
+    pub part: Option<Part>,
+    /// Wall-clock limit of the parse and of each part. A part that runs out of time keeps running
+    /// in the background until it is done.
+    pub timeout: Option<Duration>,
+    /// Whether to also run the [`Solution::VARIANTS`].
+    pub variants: bool,
+}
+
+impl Args {
+    pub const USAGE: &'static str =
+        "usage: [--input <file> | --stdin] [--part 1|2] [--timeout <seconds>] [--variants]";
+
+    /// Parses `--input <file>`, `--stdin`, `--part 1|2`, `--timeout <seconds>` and `--variants`.
+    ///
+    /// # Errors
+    ///
+    /// On unknown or incomplete arguments.
+    pub fn parse(args: impl IntoIterator<Item = String>) -> Result<Self, AocError> {
+        let mut parsed = Self::default();
+        let mut args = args.into_iter();
+        while let Some(arg) = args.next() {
+            let mut value = || {
+                args.next()
+                    .ok_or_else(|| AocError::InvalidArgument(format!("{arg} needs a value")))
+            };
+            match arg.as_str() {
+                "--input" => parsed.input = Some(InputArg::File(value()?.into())),
+                "--stdin" => parsed.input = Some(InputArg::Stdin),
+                "--variants" => parsed.variants = true,
+                "--part" => parsed.part = Some(value()?.parse()?),
+                "--timeout" => {
+                    let value = value()?;
+                    let timeout = value
+                        .parse()
+                        .ok()
+                        .and_then(|secs| Duration::try_from_secs_f64(secs).ok())
+                        .filter(|timeout| !timeout.is_zero())
+                        .ok_or_else(|| {
+                            AocError::InvalidArgument(format!("`{value}` is not a timeout"))
+                        })?;
+                    parsed.timeout = Some(timeout);
+                }
+                _ => {
+                    return Err(AocError::InvalidArgument(format!(
+                        "unknown argument `{arg}`"
+                    )))
+                }
+            }
+        }
+        Ok(parsed)
+    }
+
+    /// Parses the process arguments, exiting with the usage on failure.
+    #[must_use]
+    pub fn from_env() -> Self {
+        Self::parse(env::args().skip(1)).unwrap_or_else(|e| {
+            eprintln!("{e}\n{}", Self::USAGE);
+            std::process::exit(2)
+        })
+    }
+
+    /// Whether `part` should run, i.e. no `--part` was given or it names `part`.
+    #[must_use]
+    pub fn runs(&self, part: Part) -> bool {
+        self.part.is_none_or(|p| p == part)
+    }
+
+    /// Reads the input from `--input` or `--stdin` if given, and from [`try_get_input`]
+    /// otherwise. Either way it is [`normalize`]d.
+    ///
+    /// # Errors
+    ///
+    /// If the input cannot be read.
+    pub fn try_input(&self, puzzle: Puzzle) -> Result<String, AocError> {
+        let input = match &self.input {
+            Some(InputArg::File(path)) => InputFile::new(path).get(puzzle)?,
+            Some(InputArg::Stdin) => Stdin.get(puzzle)?,
+            None => return try_get_input(puzzle),
+        };
+        input
+            .map(|input| normalize(&input))
+            .ok_or(AocError::NoInput { puzzle })
+    }
+
+    /// Panicking version of [`Args::try_input`].
+    ///
+    /// # Panics
+    ///
+    /// If the input cannot be read.
+    #[must_use]
+    pub fn input(&self, puzzle: Puzzle) -> String {
+        self.try_input(puzzle).unwrap_or_else(|e| panic!("{e}"))
+    }
+}
+
+/// The answer to one part of a puzzle.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub enum Answer {
+    Number(i128),
+    Text(String),
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Number(n) => write!(f, "{n}"),
+            Answer::Text(s) => f.write_str(s),
+        }
+    }
+}
+
+macro_rules! answer_from_number {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(n: $t) -> Self {
+                    Answer::Number(n.into())
+                }
+            }
+        )*
+    };
+}
+
+answer_from_number!(u8, u16, u32, u64, i8, i16, i32, i64);
+
+impl From<usize> for Answer {
+    fn from(n: usize) -> Self {
+        Answer::Number(n.try_into().expect("usize fits in i128"))
+    }
+}
+
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        Answer::Text(s)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Self {
+        Answer::Text(s.to_string())
+    }
+}
+
+/// A day's puzzle: the input is parsed once and both parts are solved from the parsed form.
+pub trait Solution: 'static {
+    /// The parsed input, which may borrow from the input text.
+    type Parsed<'i>;
+
+    /// Other ways to solve the parts, run with `--variants` and checked against `part_one` and
+    /// `part_two`.
+    const VARIANTS: &'static [Variant<Self>] = &[];
+
+    fn parse(input: &str) -> Self::Parsed<'_>;
+    fn part_one(parsed: &Self::Parsed<'_>) -> Answer;
+    fn part_two(parsed: &Self::Parsed<'_>) -> Answer;
+}
+
+/// Solves a part of `S` from its parsed input.
+pub type PartSolver<S> = fn(&<S as Solution>::Parsed<'_>) -> Answer;
+
+/// An alternative solver of a part, e.g. a naive one to compare a smarter one with.
+pub struct Variant<S: Solution + ?Sized> {
+    pub part: Part,
+    /// A short name shown next to the part, e.g. `"brute force"`.
+    pub name: &'static str,
+    pub solve: PartSolver<S>,
+}
+
+/// Runs the parts of `S` selected by the command-line [`Args`] on the input of `puzzle` and prints
+/// the answers.
+pub fn run<S: Solution>(puzzle: Puzzle) {
+    let args = Args::from_env();
+    let input = args.input(puzzle);
+    solve::<S>(&input, &args, &mut |part, variant, solved| {
+        let variant = variant.map_or_else(String::new, |variant| format!(" ({variant})"));
+        match solved {
+            Ok((answer, ..)) => println!("{part}{variant}: {answer}"),
+            Err(failure) => println!("{part}{variant}: {failure}"),
+        }
+    });
+}
+
+/// Why a part has no answer.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum Failure {
+    /// The part, or the parse, still calls `todo!()` or `unimplemented!()`.
+    Unimplemented,
+    /// The part, or the parse, panicked; `location` is where, if known.
+    Panicked {
+        message: String,
+        location: Option<String>,
+    },
+    /// The part, including the parse, took longer than this.
+    TimedOut(Duration),
+}
+
+impl Failure {
+    /// Classifies the payload of a panic, telling `todo!()` and `unimplemented!()` apart.
+    #[must_use]
+    pub fn from_panic(payload: &(dyn Any + Send), location: Option<String>) -> Self {
+        let message = payload
+            .downcast_ref::<&str>()
+            .map(|message| (*message).to_string())
+            .or_else(|| payload.downcast_ref::<String>().cloned())
+            .unwrap_or_default();
+        if message.starts_with("not yet implemented") || message.starts_with("not implemented") {
+            Failure::Unimplemented
+        } else {
+            Failure::Panicked { message, location }
+        }
+    }
+}
+
+impl Display for Failure {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Failure::Unimplemented => f.write_str("not implemented"),
+            Failure::Panicked {
+                message,
+                location: Some(location),
+            } => write!(f, "panicked at {location}: {message}"),
+            Failure::Panicked {
+                message,
+                location: None,
+            } => write!(f, "panicked: {message}"),
+            Failure::TimedOut(timeout) => write!(f, "timed out after {timeout:?}"),
+        }
+    }
+}
+
+thread_local! {
+    /// Whether this thread is in [`catch`], whose panics are not printed.
+    static CATCHING: Cell<bool> = const { Cell::new(false) };
+    /// Where the last panic in [`catch`] on this thread happened.
+    static PANIC_LOCATION: Cell<Option<String>> = const { Cell::new(None) };
+}
+
+/// Runs `f`, turning a panic into a [`Failure`] instead of printing it.
+fn catch<T>(f: impl FnOnce() -> T) -> Result<T, Failure> {
+    static HOOK: Once = Once::new();
+    HOOK.call_once(|| {
+        let previous = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if CATCHING.get() {
+                PANIC_LOCATION.set(info.location().map(ToString::to_string));
+            } else {
+                previous(info);
+            }
+        }));
+    });
+    let catching = CATCHING.replace(true);
+    let result = panic::catch_unwind(AssertUnwindSafe(f));
+    CATCHING.set(catching);
+    result.map_err(|payload| Failure::from_panic(&*payload, PANIC_LOCATION.take()))
+}
+
+/// Solves the parts selected by `args`, and their variants if it asks for them, passing each
+/// answer and the time it took to `solved` as soon as it is known. `input` is parsed once, and the
+/// times leave the parse out. With a timeout, the parse and the solvers run on a worker thread
+/// that is abandoned if the parse or a solver runs out of time, and a new worker parses `input`
+/// again for the solvers left.
+fn solve<S: Solution>(input: &str, args: &Args, solved: Solved<'_>) {
+    let solvers: Vec<(Part, Option<&'static str>, PartSolver<S>)> = selected(args.part)
+        .flat_map(|p| {
+            let variants = S::VARIANTS
+                .iter()
+                .filter(move |variant| args.variants && variant.part == p)
+                .map(move |variant| (p, Some(variant.name), variant.solve));
+            iter::once((p, None, part_solver::<S>(p))).chain(variants)
+        })
+        .collect();
+    let Some(timeout) = args.timeout else {
+        match catch(|| S::parse(input)) {
+            Ok(parsed) => {
+                for (p, variant, solver) in solvers {
+                    solved(p, variant, catch(|| measured(|| solver(&parsed))));
+                }
+            }
+            Err(failure) => {
+                for (p, variant, _) in solvers {
+                    solved(p, variant, Err(failure.clone()));
+                }
+            }
+        }
+        return;
+    };
+    let mut solvers = solvers.into_iter();
+    while solvers.len() > 0 {
+        let (parsed_sender, parsed_receiver) = mpsc::channel();
+        let (sender, receiver) = mpsc::channel();
+        let input = input.to_string();
+        let left: Vec<PartSolver<S>> = solvers.as_slice().iter().map(|s| s.2).collect();
+        thread::spawn(move || {
+            let parsed = match catch(|| S::parse(&input)) {
+                Ok(parsed) => parsed,
+                Err(failure) => {
+                    let _ = parsed_sender.send(Err(failure));
+                    return;
+                }
+            };
+            let _ = parsed_sender.send(Ok(()));
+            for solver in left {
+                // After a timeout nobody is listening anymore, and the solvers left are given to
+                // a new worker.
+                if sender.send(catch(|| measured(|| solver(&parsed)))).is_err() {
+                    return;
+                }
+            }
+        });
+        let parsed = parsed_receiver
+            .recv_timeout(timeout)
+            .unwrap_or(Err(Failure::TimedOut(timeout)));
+        if let Err(failure) = parsed {
+            for (p, variant, _) in solvers {
+                solved(p, variant, Err(failure.clone()));
+            }
+            return;
+        }
+        for (p, variant, _) in solvers.by_ref() {
+            let Ok(answer) = receiver.recv_timeout(timeout) else {
+                solved(p, variant, Err(Failure::TimedOut(timeout)));
+                break;
+            };
+            solved(p, variant, answer);
+        }
+    }
+}
+
+/// Runs `f`, timing it and, with the `alloc-stats` feature, counting what it allocates.
+fn measured<T>(f: impl FnOnce() -> T) -> (T, Duration, Option<AllocStats>) {
+    let ((value, elapsed), alloc) = alloc_stats::measure(|| timed(f));
+    (value, elapsed, alloc)
+}
+
+fn timed<T>(f: impl FnOnce() -> T) -> (T, Duration) {
+    let start = Instant::now();
+    let value = f();
+    (value, start.elapsed())
+}
+
+fn part_solver<S: Solution>(part: Part) -> PartSolver<S> {
+    match part {
+        Part::One => S::part_one,
+        Part::Two => S::part_two,
+    }
+}
+
+/// `part`, or both parts if it is `None`.
+fn selected(part: Option<Part>) -> impl Iterator<Item = Part> {
+    Part::ALL
+        .into_iter()
+        .filter(move |&p| part.is_none_or(|part| part == p))
+}
+
+/// Times the parse and `part`, or both parts if it is `None`, repeating each up to `runs` times.
+/// The parts are skipped if the parse fails.
+fn bench_phases<S: Solution>(input: &str, part: Option<Part>, runs: u32) -> Vec<Benched> {
+    let parse = catch(|| {
+        bench::measure(runs, || {
+            hint::black_box(S::parse(hint::black_box(input)));
+        })
+    });
+    let Ok(parsed) = parse.clone().and_then(|_| catch(|| S::parse(input))) else {
+        return vec![(Phase::Parse, parse)];
+    };
+    let mut stats = vec![(Phase::Parse, parse)];
+    for p in selected(part) {
+        let part_stats = catch(|| {
+            bench::measure(runs, || {
+                hint::black_box(part_solver::<S>(p)(&parsed));
+            })
+        });
+        stats.push((Phase::Part(p), part_stats));
+    }
+    stats
+}
+
+/// A day's [`Solution`] with its type erased, so that days can be listed in a registry.
+#[derive(Clone, Copy)]
+pub struct Entry {
+    pub day: u8,
+    /// The title of the puzzle, e.g. `"Camel Cards"`.
+    pub title: &'static str,
+    solve: Solver,
+    bench: Bencher,
+}
+
+type Solver = fn(&str, &Args, Solved<'_>);
+/// Receives each answer of [`Entry::solve`], and the name of the [`Variant`] that gave it if not
+/// the main solver, with the time it took and what it allocated with the `alloc-stats` feature, or
+/// why there is none.
+pub type Solved<'a> = &'a mut dyn FnMut(
+    Part,
+    Option<&'static str>,
+    Result<(Answer, Duration, Option<AllocStats>), Failure>,
+);
+type Bencher = fn(&str, Option<Part>, u32) -> Vec<Benched>;
+/// The timings of a phase of [`Entry::bench`], or why it could not be timed.
+pub type Benched = (Phase, Result<Stats, Failure>);
+
+/// Every implemented day, in order.
+pub const DAYS: &[Entry] = &[
+    Entry::new::<day01::Day>(1, "Trebuchet?!"),
+    Entry::new::<day02::Day>(2, "Cube Conundrum"),
+    Entry::new::<day03::Day>(3, "Gear Ratios"),
+    Entry::new::<day04::Day>(4, "Scratchcards"),
+    Entry::new::<day05::Day>(5, "If You Give A Seed A Fertilizer"),
+    Entry::new::<day06::Day>(6, "Wait For It"),
+    Entry::new::<day07::Day>(7, "Camel Cards"),
+    Entry::new::<day08::Day>(8, "Haunted Wasteland"),
+    Entry::new::<day09::Day>(9, "Mirage Maintenance"),
+    Entry::new::<day10::Day>(10, "Pipe Maze"),
+    Entry::new::<day11::Day>(11, "Cosmic Expansion"),
+];
+
+impl Entry {
+    #[must_use]
+    pub const fn new<S: Solution>(day: u8, title: &'static str) -> Self {
+        Self {
+            day,
+            title,
+            solve: solve::<S>,
+            bench: bench_phases::<S>,
+        }
+    }
+
+    #[must_use]
+    pub fn puzzle(&self) -> Puzzle {
+        Puzzle {
+            year: YEAR,
+            day: self.day,
+        }
+    }
+
+    /// Solves the parts selected by `args`, and their variants with `--variants`, passing each
+    /// answer and the time it took, or the [`Failure`] to get it, to `solved` as soon as it is
+    /// known. Panics are caught, and the parse or a solver is abandoned once it has taken `--timeout`,
+    /// though it keeps running in the background and may slow down the solvers after it.
+    pub fn solve(&self, input: &str, args: &Args, solved: Solved<'_>) {
+        (self.solve)(input, args, solved);
+    }
+
+    /// Times the parse and `part`, or both parts if it is `None`, repeating each up to `runs`
+    /// times within [`bench::BUDGET`]. Panics are caught, and the parts skipped if the parse
+    /// panics.
+    #[must_use]
+    pub fn bench(&self, input: &str, part: Option<Part>, runs: u32) -> Vec<Benched> {
+        (self.bench)(input, part, runs)
+    }
+}
 
 pub fn match_and_move_1<'s, O, E: ParserError<&'s str>>(
     mut parser: impl Parser<&'s str, O, E>,
@@ -20,29 +1572,102 @@ pub fn match_and_move_1<'s, O, E: ParserError<&'s str>>(
     }
 }
 
-#[must_use]
-#[allow(clippy::missing_panics_doc)]
-pub fn day_number(file: &str) -> &str {
+/// Extracts the day number from a binary's file name, e.g. `"7"` from `src/bin/07.rs`.
+///
+/// # Errors
+///
+/// If the file stem is not a day between 1 and 25.
+pub fn try_day_number(file: &str) -> Result<&str, AocError> {
+    let invalid = || AocError::InvalidDayNumber(file.to_string());
     let prefixed_number = Path::new(file)
         .file_stem()
         .and_then(|f| f.to_str())
-        .unwrap();
-    prefixed_number.strip_prefix('0').unwrap_or(prefixed_number)
+        .ok_or_else(invalid)?;
+    let number = prefixed_number.strip_prefix('0').unwrap_or(prefixed_number);
+    parse_day(number).map_err(|_| invalid())?;
+    Ok(number)
 }
 
+/// Panicking version of [`try_day_number`].
+///
+/// # Panics
+///
+/// If the file stem is not a day between 1 and 25.
 #[must_use]
-#[allow(clippy::missing_panics_doc)]
-pub fn get_input(day_number: &str) -> String {
-    get(
-        &format!("https://adventofcode.com/2023/day/{day_number}/input"),
-        MY_COOKIE,
-    )
-    .unwrap()
+pub fn day_number(file: &str) -> &str {
+    try_day_number(file).unwrap_or_else(|e| panic!("{e}"))
+}
+
+fn parse_day(day_number: &str) -> Result<u8, AocError> {
+    day_number
+        .parse()
+        .ok()
+        .filter(|day| (1..=25).contains(day))
+        .ok_or_else(|| AocError::InvalidDayNumber(day_number.to_string()))
+}
+
+/// Makes input edited on any platform look like a download: strips a byte order mark, converts
+/// CRLF line endings to LF and removes trailing newlines, which the parsers have never seen since
+/// the original cache trimmed them.
+#[must_use]
+pub fn normalize(input: &str) -> String {
+    let input = input.strip_prefix('\u{feff}').unwrap_or(input);
+    input
+        .replace("\r\n", "\n")
+        .trim_end_matches('\n')
+        .to_string()
+}
+
+/// Gets the input for `puzzle` from the first of [`default_sources`] that has it, and
+/// [`normalize`]s it.
+///
+/// # Errors
+///
+/// If no source has the input, or if a source fails to read it.
+pub fn try_get_input(puzzle: Puzzle) -> Result<String, AocError> {
+    for source in default_sources() {
+        if let Some(input) = source.get(puzzle)? {
+            return Ok(normalize(&input));
+        }
+    }
+    Err(AocError::NoInput { puzzle })
+}
+
+/// Panicking version of [`try_get_input`].
+///
+/// # Panics
+///
+/// If no source has the input, or if a source fails to read it.
+#[must_use]
+pub fn get_input(puzzle: Puzzle) -> String {
+    try_get_input(puzzle).unwrap_or_else(|e| panic!("{e}"))
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::{day_number, match_and_move_1};
+    use crate::{
+        day_number, ensure_unlocked, error_page, match_and_move_1, normalize, normalize_cookie,
+        solve, try_day_number, Answer, AocError, Args, Clock, Failure, HttpCache, InputArg,
+        InputCache, InputDir, InputSource, Integrity, Part, Puzzle, Solution,
+    };
+    use std::{
+        fs,
+        sync::atomic::{AtomicUsize, Ordering},
+        thread,
+        time::{Duration, SystemTime, UNIX_EPOCH},
+    };
+
+    struct FakeClock(SystemTime);
+
+    impl Clock for FakeClock {
+        fn now(&self) -> SystemTime {
+            self.0
+        }
+    }
+
+    fn unix(secs: u64) -> FakeClock {
+        FakeClock(UNIX_EPOCH + Duration::from_secs(secs))
+    }
     use winnow::{error::InputError, Parser};
 
     #[test]
@@ -58,4 +1683,346 @@ mod tests {
         assert_eq!("7", day_number("/directory/files/07.rs"));
         assert_eq!("24", day_number("/directory/files/24.rs"));
     }
+
+    #[test]
+    fn rejects_invalid_day_number() {
+        for file in [
+            "/directory/files/00.rs",
+            "/directory/files/26.rs",
+            "main.rs",
+            "",
+        ] {
+            assert!(matches!(
+                try_day_number(file),
+                Err(AocError::InvalidDayNumber(_))
+            ));
+        }
+    }
+
+    #[test]
+    fn normalizes_cookie() {
+        assert_eq!(
+            normalize_cookie("abc123\n").as_deref(),
+            Some("session=abc123")
+        );
+        assert_eq!(
+            normalize_cookie("session=abc123").as_deref(),
+            Some("session=abc123")
+        );
+        assert_eq!(normalize_cookie(" \n"), None);
+    }
+
+    #[test]
+    fn puzzle_from_file() {
+        let puzzle = Puzzle::try_from_file("/directory/files/07.rs").unwrap();
+        assert_eq!(puzzle, Puzzle { year: 2023, day: 7 });
+        assert_eq!((puzzle.year(), puzzle.day()), (2023, 7));
+        assert_eq!(
+            puzzle.input_url(),
+            "https://adventofcode.com/2023/day/7/input"
+        );
+        assert!(Puzzle::new(2022, 0).is_err());
+    }
+
+    #[test]
+    fn input_dir_separates_other_years() {
+        let source = InputDir::new("inputs");
+        assert_eq!(
+            source.path(Puzzle::new(2023, 7).unwrap()),
+            std::path::Path::new("inputs/07.txt")
+        );
+        assert_eq!(
+            source.path(Puzzle::new(2022, 7).unwrap()),
+            std::path::Path::new("inputs/2022/07.txt")
+        );
+    }
+
+    #[test]
+    fn input_dir_pads_day_number() {
+        let dir = std::env::temp_dir().join(format!("aoc-2023-input-dir-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("07.txt"), "32T3K 765").unwrap();
+        let source = InputDir::new(&dir);
+        let day_7 = Puzzle::new(2023, 7).unwrap();
+        let day_8 = Puzzle::new(2023, 8).unwrap();
+        assert_eq!(source.get(day_7).unwrap().as_deref(), Some("32T3K 765"));
+        assert_eq!(source.get(day_8).unwrap(), None);
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn recognizes_error_pages() {
+        assert_eq!(
+            error_page("Puzzle inputs differ by user.  Please log in to get your puzzle input.\n"),
+            Some("Puzzle inputs differ by user.  Please log in to get your puzzle input.")
+        );
+        assert!(error_page("Please don't repeatedly request this endpoint before it unlocks! The calendar countdown is synchronized with the server time; the link will be enabled on the calendar the instant this puzzle becomes available.").is_some());
+        assert_eq!(error_page("0 3 6 9 12 15\n1 3 6 10 15 21"), None);
+    }
+
+    #[test]
+    fn input_cache_round_trip() {
+        let dir = std::env::temp_dir().join(format!("aoc-2023-input-cache-{}", std::process::id()));
+        let cache = InputCache::new(&dir);
+        let day_7 = Puzzle::new(2023, 7).unwrap();
+        let day_8 = Puzzle::new(2023, 8).unwrap();
+        assert_eq!(cache.get(day_7).unwrap(), None);
+        cache.insert(day_7, "32T3K 765").unwrap();
+        cache
+            .insert(day_8, "Please log in to get your puzzle input.")
+            .unwrap();
+        assert_eq!(cache.get(day_7).unwrap().as_deref(), Some("32T3K 765"));
+        assert!(matches!(
+            cache.get(day_8),
+            Err(AocError::ErrorPage { cached: true, .. })
+        ));
+        assert_eq!(
+            cache.read(day_8).unwrap().as_deref(),
+            Some("Please log in to get your puzzle input.")
+        );
+        assert!(cache.remove(day_8).unwrap());
+        assert!(!cache.remove(day_8).unwrap());
+        assert_eq!(cache.read(day_8).unwrap(), None);
+        assert_eq!(cache.get(day_8).unwrap(), None);
+        assert_eq!(cache.get(day_7).unwrap().as_deref(), Some("32T3K 765"));
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn input_cache_lists_and_verifies() {
+        let dir =
+            std::env::temp_dir().join(format!("aoc-2023-cache-verify-{}", std::process::id()));
+        let cache = InputCache::new(&dir);
+        let day_7 = Puzzle::new(2023, 7).unwrap();
+        let day_2 = Puzzle::new(2022, 2).unwrap();
+        cache.insert(day_7, "32T3K 765").unwrap();
+        cache.insert(day_2, "A Y").unwrap();
+        let entries = cache.entries().unwrap();
+        let listed: Vec<_> = entries.iter().map(|e| (e.puzzle, e.size)).collect();
+        assert_eq!(listed, [(day_2, Some(3)), (day_7, Some(9))]);
+
+        assert_eq!(cache.verify(day_7).unwrap(), Some(Integrity::Intact));
+        fs::write(&entries[1].path, "32T3K 766").unwrap();
+        assert!(matches!(
+            cache.verify(day_7).unwrap(),
+            Some(Integrity::Modified { .. })
+        ));
+        fs::remove_file(dir.join("input_2022_02.cache.sha256")).unwrap();
+        assert_eq!(cache.verify(day_2).unwrap(), Some(Integrity::Unhashed));
+        assert_eq!(cache.verify(Puzzle::new(2023, 8).unwrap()).unwrap(), None);
+
+        fs::remove_file(&entries[0].path).unwrap();
+        let listed: Vec<_> = cache
+            .entries()
+            .unwrap()
+            .iter()
+            .map(|e| (e.puzzle, e.size))
+            .collect();
+        assert_eq!(listed, [(day_2, None), (day_7, Some(9))]);
+        assert_eq!(cache.verify(day_2).unwrap(), Some(Integrity::Missing));
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn parses_puzzles() {
+        assert_eq!(
+            "7".parse::<Puzzle>().unwrap(),
+            Puzzle { year: 2023, day: 7 }
+        );
+        assert_eq!(
+            "2022/25".parse::<Puzzle>().unwrap(),
+            Puzzle {
+                year: 2022,
+                day: 25
+            }
+        );
+        assert!("2022/26".parse::<Puzzle>().is_err());
+        assert!(matches!(
+            "1969/1".parse::<Puzzle>(),
+            Err(AocError::InvalidYear(1969))
+        ));
+        assert!("seven".parse::<Puzzle>().is_err());
+        let puzzle = Puzzle::new(2022, 25).unwrap();
+        assert_eq!(Puzzle::from_input_url(&puzzle.input_url()), Some(puzzle));
+    }
+
+    #[test]
+    fn puzzles_unlock_at_midnight_eastern() {
+        let day_1 = Puzzle::new(2023, 1).unwrap();
+        assert_eq!(day_1.unlocks_at(), unix(1_701_406_800).0);
+        let day_25 = Puzzle::new(2015, 25).unwrap();
+        assert_eq!(day_25.unlocks_at(), unix(1_451_019_600).0);
+    }
+
+    #[test]
+    fn guards_locked_puzzles() {
+        let day_1 = Puzzle::new(2023, 1).unwrap();
+        let Err(AocError::Locked { remaining, .. }) = ensure_unlocked(day_1, &unix(1_701_320_399))
+        else {
+            panic!("day 1 should be locked");
+        };
+        assert_eq!(remaining, Duration::from_secs(86_401));
+        assert_eq!(
+            AocError::Locked {
+                puzzle: day_1,
+                remaining
+            }
+            .to_string(),
+            "2023 day 1 unlocks in 1d 00h 00m 01s"
+        );
+        assert!(ensure_unlocked(day_1, &unix(1_701_406_800)).is_ok());
+        assert!(ensure_unlocked(day_1, &unix(1_701_406_801)).is_ok());
+    }
+
+    #[test]
+    fn http_cache_does_not_request_locked_puzzles() {
+        let dir = std::env::temp_dir().join(format!("aoc-2023-http-cache-{}", std::process::id()));
+        let source = HttpCache::new(InputCache::new(&dir)).with_clock(unix(1_701_406_799));
+        let day_1 = Puzzle::new(2023, 1).unwrap();
+        assert!(matches!(
+            source.get(day_1),
+            Err(AocError::Locked { remaining, .. }) if remaining == Duration::from_secs(1)
+        ));
+    }
+
+    #[test]
+    fn parses_args() {
+        let parse = |args: &[&str]| Args::parse(args.iter().map(ToString::to_string));
+        assert_eq!(parse(&[]).unwrap(), Args::default());
+        let args = parse(&["--input", "edge.txt", "--part", "2"]).unwrap();
+        assert_eq!(args.input, Some(InputArg::File("edge.txt".into())));
+        assert!(!args.runs(Part::One));
+        assert!(args.runs(Part::Two));
+        assert_eq!(parse(&["--stdin"]).unwrap().input, Some(InputArg::Stdin));
+        assert!(parse(&["--part", "3"]).is_err());
+        assert!(parse(&["--input"]).is_err());
+        assert!(parse(&["--verbose"]).is_err());
+        let timeout = |secs| parse(&["--timeout", secs]).map(|args| args.timeout);
+        assert_eq!(timeout("1.5").unwrap(), Some(Duration::from_millis(1500)));
+        assert!(timeout("0").is_err());
+        assert!(timeout("-1").is_err());
+    }
+
+    #[test]
+    fn normalizes_input() {
+        assert_eq!(
+            normalize("\u{feff}RL\r\n\r\nAAA = (BBB, CCC)\r\n"),
+            "RL\n\nAAA = (BBB, CCC)"
+        );
+        assert_eq!(normalize("32T3K 765\n\n\n"), "32T3K 765");
+        assert_eq!(normalize("a\rb"), "a\rb");
+        assert_eq!(normalize("0 3 6"), "0 3 6");
+    }
+
+    #[test]
+    fn answers_from_numbers_and_text() {
+        assert_eq!(Answer::from(4361_u32), Answer::Number(4361));
+        assert_eq!(Answer::from(-2_i32).to_string(), "-2");
+        assert_eq!(Answer::from(usize::MAX).to_string(), usize::MAX.to_string());
+        assert_eq!(Answer::from("LDRUL").to_string(), "LDRUL");
+    }
+
+    /// Part one panics on empty input and otherwise sleeps for as many milliseconds as the input
+    /// says; part two is not written yet.
+    struct Flaky;
+
+    impl Solution for Flaky {
+        type Parsed<'i> = &'i str;
+
+        fn parse(input: &str) -> &str {
+            input
+        }
+
+        fn part_one(input: &&str) -> Answer {
+            let millis = input.parse().expect("a number of milliseconds");
+            thread::sleep(Duration::from_millis(millis));
+            millis.into()
+        }
+
+        fn part_two(_: &&str) -> Answer {
+            todo!()
+        }
+    }
+
+    fn solve_flaky(input: &str, timeout: Option<Duration>) -> Vec<(Part, Result<Answer, Failure>)> {
+        let mut solved = Vec::new();
+        let args = Args {
+            timeout,
+            ..Args::default()
+        };
+        solve::<Flaky>(input, &args, &mut |part, _, answer| {
+            solved.push((part, answer.map(|(answer, ..)| answer)));
+        });
+        solved
+    }
+
+    #[test]
+    fn catches_failing_parts() {
+        let timeout = Some(Duration::from_secs(10));
+        for timeout in [None, timeout] {
+            assert_eq!(
+                solve_flaky("1", timeout),
+                [
+                    (Part::One, Ok(1.into())),
+                    (Part::Two, Err(Failure::Unimplemented))
+                ]
+            );
+            let solved = solve_flaky("", timeout);
+            let Err(Failure::Panicked { message, location }) = &solved[0].1 else {
+                panic!("part one did not panic: {solved:?}");
+            };
+            assert!(message.starts_with("a number of milliseconds"));
+            assert!(location.as_deref().unwrap().starts_with("src/lib.rs:"));
+        }
+        let timeout = Duration::from_millis(50);
+        assert_eq!(
+            solve_flaky("5000", Some(timeout)),
+            [
+                (Part::One, Err(Failure::TimedOut(timeout))),
+                (Part::Two, Err(Failure::Unimplemented))
+            ]
+        );
+    }
+
+    static SLOW_PARSES: AtomicUsize = AtomicUsize::new(0);
+
+    /// Takes 100ms to parse and no time to solve.
+    struct SlowParse;
+
+    impl Solution for SlowParse {
+        type Parsed<'i> = ();
+
+        fn parse(_: &str) {
+            SLOW_PARSES.fetch_add(1, Ordering::Relaxed);
+            thread::sleep(Duration::from_millis(100));
+        }
+
+        fn part_one((): &()) -> Answer {
+            1.into()
+        }
+
+        fn part_two((): &()) -> Answer {
+            2.into()
+        }
+    }
+
+    #[test]
+    fn parses_once_outside_the_times() {
+        for timeout in [None, Some(Duration::from_secs(10))] {
+            let parses = SLOW_PARSES.load(Ordering::Relaxed);
+            let args = Args {
+                timeout,
+                ..Args::default()
+            };
+            let mut solved = Vec::new();
+            solve::<SlowParse>("", &args, &mut |part, _, answer| {
+                solved.push((part, answer.unwrap()));
+            });
+            assert_eq!(SLOW_PARSES.load(Ordering::Relaxed), parses + 1);
+            assert_eq!(solved.len(), 2);
+            for (_, (_, elapsed, _)) in solved {
+                assert!(elapsed < Duration::from_millis(100), "{elapsed:?}");
+            }
+        }
+    }
 }
@@ -0,0 +1,176 @@
+//! Scaffolding for a new day: its module from `00.rs.template`, its binary, its registration in
+//! [`DAYS`](crate::DAYS) and an example to fill in.
+
+use crate::{io_error, read_file, write_file, AocError};
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+/// The module template, relative to the crate root. `{{day}}` and `{{title}}` are replaced by the
+/// day number and puzzle title.
+pub const TEMPLATE: &str = "00.rs.template";
+
+const EXAMPLE_ANSWERS: &str = "# Expected answers to 1.txt, as printed by `aoc`. Leave a part \
+                               empty until it is known.\npart_one = \"\"\npart_two = \"\"\n";
+
+/// Creates the module, binary and example of `day` under the crate root `root` and registers the
+/// module in `src/lib.rs`, returning the files created or changed.
+///
+/// # Errors
+///
+/// If any of the files already exists or the day is already registered, in which case nothing is
+/// written, or if reading or writing a file fails.
+pub fn new_day(root: &Path, day: u8, title: &str) -> Result<Vec<PathBuf>, AocError> {
+    let module = root.join(format!("src/day{day:02}.rs"));
+    let binary = root.join(format!("src/bin/{day:02}.rs"));
+    let examples = root.join(format!("examples/{day:02}"));
+    let example = examples.join("1.txt");
+    let example_answers = examples.join("1.toml");
+    let lib = root.join("src/lib.rs");
+
+    let created = [&module, &binary, &example, &example_answers];
+    if let Some(path) = created.into_iter().find(|path| path.exists()) {
+        return Err(AocError::AlreadyExists { path: path.clone() });
+    }
+    let registered = register(&read_file(&lib)?, day, title)
+        .ok_or_else(|| AocError::AlreadyExists { path: lib.clone() })?;
+    let template = read_file(&root.join(TEMPLATE))?
+        .replace("{{day}}", &day.to_string())
+        .replace("{{title}}", title);
+
+    write_file(&module, &template)?;
+    write_file(
+        &binary,
+        &format!(
+            "use aoc_2023::{{day{day:02}::Day, Puzzle}};\n\nfn main() {{\n    \
+             aoc_2023::run::<Day>(Puzzle::from_file(file!()));\n}}\n"
+        ),
+    )?;
+    fs::create_dir_all(&examples).map_err(io_error(&examples))?;
+    write_file(&example, "")?;
+    write_file(&example_answers, EXAMPLE_ANSWERS)?;
+    write_file(&lib, &registered)?;
+    Ok(vec![module, binary, example, example_answers, lib])
+}
+
+/// `lib` with `pub mod dayNN;` and the [`Entry`](crate::Entry) of `day` inserted in order, or
+/// `None` if the day is already registered.
+fn register(lib: &str, day: u8, title: &str) -> Option<String> {
+    let module = format!("pub mod day{day:02};");
+    let entry = format!("    Entry::new::<day{day:02}::Day>({day}, {title:?}),");
+    if lib.lines().any(|line| line == module) {
+        return None;
+    }
+    let mut lines: Vec<_> = lib.lines().collect();
+    insert_sorted(&mut lines, "pub mod day", &module);
+    insert_sorted(&mut lines, "    Entry::new::<day", &entry);
+    Some(lines.join("\n") + "\n")
+}
+
+/// Inserts `line` among the lines starting with `prefix`, which are sorted, before the first that
+/// sorts after it.
+fn insert_sorted<'a>(lines: &mut Vec<&'a str>, prefix: &str, line: &'a str) {
+    let matching = |existing: &&str| existing.starts_with(prefix);
+    let i = lines
+        .iter()
+        .position(|existing| matching(existing) && *existing > line)
+        .or_else(|| lines.iter().rposition(matching).map(|i| i + 1))
+        .unwrap_or(lines.len());
+    lines.insert(i, line);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{new_day, register, TEMPLATE};
+    use crate::AocError;
+    use std::{fs, path::Path, process::Command};
+
+    const LIB: &str = "pub mod answers;
+pub mod day01;
+pub mod day03;
+
+pub const DAYS: &[Entry] = &[
+    Entry::new::<day01::Day>(1, \"Trebuchet?!\"),
+    Entry::new::<day03::Day>(3, \"Gear Ratios\"),
+];
+";
+
+    #[test]
+    fn registers_days_in_order() {
+        let lib = register(LIB, 2, "Cube \"Conundrum\"").unwrap();
+        assert!(lib.contains("pub mod day01;\npub mod day02;\npub mod day03;\n"));
+        assert!(lib.contains(
+            "(1, \"Trebuchet?!\"),\n    Entry::new::<day02::Day>(2, \"Cube \\\"Conundrum\\\"\"),\n"
+        ));
+        let lib = register(&lib, 12, "Hot Springs").unwrap();
+        assert!(lib.contains("pub mod day03;\npub mod day12;\n\n"));
+        assert!(lib.contains("Entry::new::<day12::Day>(12, \"Hot Springs\"),\n];\n"));
+        assert_eq!(register(&lib, 3, "Gear Ratios"), None);
+    }
+
+    #[test]
+    fn scaffolds_a_day_without_overwriting() {
+        let root = std::env::temp_dir().join(format!("aoc-scaffold-test-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(root.join("src/bin")).unwrap();
+        fs::write(root.join("src/lib.rs"), LIB).unwrap();
+        fs::write(root.join(TEMPLATE), "//! Day {{day}}: {{title}}\n").unwrap();
+
+        assert_eq!(new_day(&root, 12, "Hot Springs").unwrap().len(), 5);
+        assert_eq!(
+            fs::read_to_string(root.join("src/day12.rs")).unwrap(),
+            "//! Day 12: Hot Springs\n"
+        );
+        assert!(fs::read_to_string(root.join("src/bin/12.rs"))
+            .unwrap()
+            .contains("aoc_2023::{day12::Day, Puzzle}"));
+        assert_eq!(
+            fs::read_to_string(root.join("examples/12/1.txt")).unwrap(),
+            ""
+        );
+        assert!(matches!(
+            new_day(&root, 12, "Hot Springs"),
+            Err(AocError::AlreadyExists { .. })
+        ));
+
+        fs::remove_file(root.join("src/day12.rs")).unwrap();
+        fs::remove_file(root.join("src/bin/12.rs")).unwrap();
+        fs::remove_dir_all(root.join("examples")).unwrap();
+        assert!(matches!(
+            new_day(&root, 12, "Hot Springs"),
+            Err(AocError::AlreadyExists { path }) if path.ends_with("lib.rs")
+        ));
+        assert!(!root.join("src/day12.rs").exists());
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn scaffolds_formatted_code() {
+        let root =
+            std::env::temp_dir().join(format!("aoc-scaffold-fmt-test-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(root.join("src/bin")).unwrap();
+        fs::write(root.join("src/lib.rs"), LIB).unwrap();
+        fs::copy(
+            Path::new(env!("CARGO_MANIFEST_DIR")).join(TEMPLATE),
+            root.join(TEMPLATE),
+        )
+        .unwrap();
+        new_day(&root, 12, "Hot Springs").unwrap();
+
+        let rustfmt = std::env::var_os("RUSTFMT").unwrap_or_else(|| "rustfmt".into());
+        let output = Command::new(rustfmt)
+            .args(["--edition", "2021", "--check"])
+            .arg(root.join("src/day12.rs"))
+            .arg(root.join("src/bin/12.rs"))
+            .output()
+            .unwrap();
+        assert!(
+            output.status.success(),
+            "{}",
+            String::from_utf8_lossy(&output.stdout)
+        );
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
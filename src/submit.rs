@@ -0,0 +1,373 @@
+//! Submitting answers to adventofcode.com, and the outcomes of earlier submissions kept in
+//! `submissions.toml` so that an answer known to be wrong is never sent again.
+
+use crate::{day_key, read_toml, write_toml, Answer, AocError, Client, Part, Puzzle};
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::BTreeMap,
+    env,
+    fmt::{self, Display, Formatter},
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+/// Environment variable overriding [`DEFAULT_SUBMISSIONS_FILE`].
+pub const SUBMISSIONS_ENV: &str = "AOC_SUBMISSIONS";
+/// Submissions file used when [`SUBMISSIONS_ENV`] is not set.
+pub const DEFAULT_SUBMISSIONS_FILE: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/submissions.toml");
+
+const HEADER: &str =
+    "# Answers sent by `aoc submit` and how they fared, so that none is sent twice.\n\n";
+
+/// What adventofcode.com made of a submitted answer.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum Outcome {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// Wrong, without a hint in which direction.
+    Wrong,
+    /// An answer was submitted too recently; the next may be sent after this long.
+    Wait(Duration),
+    /// The part is not the one being solved: it is already solved, or part one is not yet.
+    WrongLevel,
+}
+
+impl Outcome {
+    /// Parses the page adventofcode.com answers a submission with.
+    #[must_use]
+    pub fn parse(page: &str) -> Option<Self> {
+        if page.contains("That's the right answer") {
+            Some(Outcome::Correct)
+        } else if page.contains("That's not the right answer") {
+            Some(if page.contains("your answer is too high") {
+                Outcome::TooHigh
+            } else if page.contains("your answer is too low") {
+                Outcome::TooLow
+            } else {
+                Outcome::Wrong
+            })
+        } else if page.contains("You gave an answer too recently") {
+            let left = page
+                .split_once("You have ")?
+                .1
+                .split_once(" left to wait")?
+                .0;
+            Some(Outcome::Wait(parse_wait(left)?))
+        } else if page.contains("You don't seem to be solving the right level") {
+            Some(Outcome::WrongLevel)
+        } else {
+            None
+        }
+    }
+}
+
+/// Parses a wait like `1m 23s`.
+fn parse_wait(left: &str) -> Option<Duration> {
+    left.split_whitespace()
+        .try_fold(Duration::ZERO, |wait, amount| {
+            let unit = match amount.chars().last()? {
+                'h' => 3600,
+                'm' => 60,
+                's' => 1,
+                _ => return None,
+            };
+            let n: u64 = amount[..amount.len() - 1].parse().ok()?;
+            Some(wait + Duration::from_secs(n * unit))
+        })
+}
+
+impl Display for Outcome {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Outcome::Correct => f.write_str("correct"),
+            Outcome::TooHigh => f.write_str("wrong, too high"),
+            Outcome::TooLow => f.write_str("wrong, too low"),
+            Outcome::Wrong => f.write_str("wrong"),
+            Outcome::Wait(wait) => write!(
+                f,
+                "submitted too recently, wait {}s before trying again",
+                wait.as_secs()
+            ),
+            Outcome::WrongLevel => f.write_str("not the part being solved, is it solved already?"),
+        }
+    }
+}
+
+/// The answers submitted for a part, by outcome.
+#[derive(Debug, Default, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct PartSubmissions {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub correct: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub too_high: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub too_low: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub wrong: Vec<String>,
+}
+
+impl PartSubmissions {
+    fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// The outcome `answer` is known to have: that of an earlier submission of it, too high or
+    /// too low if it is beyond an answer that was, or wrong if another answer was correct.
+    fn known(&self, answer: &Answer) -> Option<Outcome> {
+        let text = answer.to_string();
+        let beyond = |bounds: &[String], beyond: fn(i128, i128) -> bool| {
+            bounds.iter().any(|bound| match (answer, bound.parse()) {
+                (Answer::Number(n), Ok(bound)) => beyond(*n, bound),
+                _ => *bound == text,
+            })
+        };
+        if self.correct.as_ref() == Some(&text) {
+            Some(Outcome::Correct)
+        } else if self.correct.is_some() || self.wrong.contains(&text) {
+            Some(Outcome::Wrong)
+        } else if beyond(&self.too_high, |n, bound| n >= bound) {
+            Some(Outcome::TooHigh)
+        } else if beyond(&self.too_low, |n, bound| n <= bound) {
+            Some(Outcome::TooLow)
+        } else {
+            None
+        }
+    }
+}
+
+/// The submissions to both parts of a day.
+#[derive(Debug, Default, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct DaySubmissions {
+    #[serde(default, skip_serializing_if = "PartSubmissions::is_empty")]
+    pub part_one: PartSubmissions,
+    #[serde(default, skip_serializing_if = "PartSubmissions::is_empty")]
+    pub part_two: PartSubmissions,
+}
+
+impl DaySubmissions {
+    #[must_use]
+    pub fn get(&self, part: Part) -> &PartSubmissions {
+        match part {
+            Part::One => &self.part_one,
+            Part::Two => &self.part_two,
+        }
+    }
+
+    fn get_mut(&mut self, part: Part) -> &mut PartSubmissions {
+        match part {
+            Part::One => &mut self.part_one,
+            Part::Two => &mut self.part_two,
+        }
+    }
+}
+
+/// The submissions of every puzzle, stored as a `[YYYY.dayNN]` table per day, so that the days of
+/// different years are kept apart.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Submissions {
+    path: PathBuf,
+    years: BTreeMap<String, BTreeMap<String, DaySubmissions>>,
+}
+
+impl Submissions {
+    /// Loads the submissions from `path`, which need not exist yet.
+    ///
+    /// # Errors
+    ///
+    /// If the file cannot be read or is not valid.
+    pub fn load(path: impl Into<PathBuf>) -> Result<Self, AocError> {
+        let path = path.into();
+        let years = read_toml(&path)?;
+        Ok(Self { path, years })
+    }
+
+    /// Loads the submissions from [`SUBMISSIONS_ENV`] or [`DEFAULT_SUBMISSIONS_FILE`].
+    ///
+    /// # Errors
+    ///
+    /// See [`Submissions::load`].
+    pub fn default_location() -> Result<Self, AocError> {
+        Self::load(
+            env::var_os(SUBMISSIONS_ENV)
+                .map_or_else(|| DEFAULT_SUBMISSIONS_FILE.into(), PathBuf::from),
+        )
+    }
+
+    #[must_use]
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    #[must_use]
+    pub fn day(&self, puzzle: Puzzle) -> Option<&DaySubmissions> {
+        self.years
+            .get(&puzzle.year().to_string())?
+            .get(&day_key(puzzle.day()))
+    }
+
+    /// The outcome submitting `answer` to `part` of `puzzle` would have, if the earlier
+    /// submissions tell.
+    #[must_use]
+    pub fn known(&self, puzzle: Puzzle, part: Part, answer: &Answer) -> Option<Outcome> {
+        self.day(puzzle)?.get(part).known(answer)
+    }
+
+    /// Records that submitting `answer` to `part` of `puzzle` had `outcome`. Waits are not
+    /// recorded, as they say nothing about the answer.
+    pub fn record(&mut self, puzzle: Puzzle, part: Part, answer: &Answer, outcome: &Outcome) {
+        let answer = answer.to_string();
+        let submissions = self
+            .years
+            .entry(puzzle.year().to_string())
+            .or_default()
+            .entry(day_key(puzzle.day()))
+            .or_default()
+            .get_mut(part);
+        match outcome {
+            Outcome::Correct => submissions.correct = Some(answer),
+            Outcome::TooHigh => submissions.too_high.push(answer),
+            Outcome::TooLow => submissions.too_low.push(answer),
+            Outcome::Wrong => submissions.wrong.push(answer),
+            Outcome::Wait(_) | Outcome::WrongLevel => {}
+        }
+    }
+
+    /// Writes the submissions back to their file.
+    ///
+    /// # Errors
+    ///
+    /// If the file cannot be written.
+    pub fn save(&self) -> Result<(), AocError> {
+        write_toml(&self.path, HEADER, &self.years)
+    }
+}
+
+/// The outcome of [`submit`].
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Submission {
+    pub outcome: Outcome,
+    /// Whether the answer was sent, rather than its outcome known from an earlier submission.
+    pub sent: bool,
+}
+
+/// Submits `answer` to `part` of `puzzle` with `client`, unless `submissions` already tell its
+/// outcome, and records the outcome in them. The session cookie is only looked up with `cookie`
+/// when the answer is sent.
+///
+/// # Errors
+///
+/// If there is no cookie, or if the request fails or its answer is not understood.
+pub fn submit(
+    client: &Client,
+    submissions: &mut Submissions,
+    puzzle: Puzzle,
+    part: Part,
+    answer: &Answer,
+    cookie: impl FnOnce() -> Result<String, AocError>,
+) -> Result<Submission, AocError> {
+    if let Some(outcome) = submissions.known(puzzle, part, answer) {
+        return Ok(Submission {
+            outcome,
+            sent: false,
+        });
+    }
+    let outcome = client.submit(puzzle, part, answer, &cookie()?)?;
+    submissions.record(puzzle, part, answer, &outcome);
+    Ok(Submission {
+        outcome,
+        sent: true,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Outcome, Submissions};
+    use crate::{Answer, Part, Puzzle};
+    use std::{fs, time::Duration};
+
+    #[test]
+    fn parses_outcomes() {
+        let page = |text: &str| format!("<main>\n<article><p>{text}</p></article>\n</main>");
+        assert_eq!(
+            Outcome::parse(&page(
+                "That's the right answer!  You are one gold star closer to restoring snow \
+                 operations."
+            )),
+            Some(Outcome::Correct)
+        );
+        assert_eq!(
+            Outcome::parse(&page(
+                "That's not the right answer; your answer is too low.  If you're stuck, make sure \
+                 you're using the full input data; please wait one minute before trying again."
+            )),
+            Some(Outcome::TooLow)
+        );
+        assert_eq!(
+            Outcome::parse(&page("That's not the right answer.  If you're stuck, ...")),
+            Some(Outcome::Wrong)
+        );
+        assert_eq!(
+            Outcome::parse(&page(
+                "You gave an answer too recently; you have to wait after submitting an answer \
+                 before trying again.  You have 1m 23s left to wait."
+            )),
+            Some(Outcome::Wait(Duration::from_secs(83)))
+        );
+        assert_eq!(
+            Outcome::parse(&page(
+                "You don't seem to be solving the right level.  Did you already complete it?"
+            )),
+            Some(Outcome::WrongLevel)
+        );
+        assert_eq!(Outcome::parse(&page("Please log in.")), None);
+    }
+
+    #[test]
+    fn records_and_knows_outcomes() {
+        let dir = std::env::temp_dir().join(format!("aoc-submit-test-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("submissions.toml");
+        let _ = fs::remove_file(&path);
+
+        let day_5 = Puzzle::new(2023, 5).unwrap();
+        let mut submissions = Submissions::load(&path).unwrap();
+        let known = |submissions: &Submissions, answer: i32| {
+            submissions.known(day_5, Part::Two, &answer.into())
+        };
+        assert_eq!(known(&submissions, 100), None);
+        submissions.record(day_5, Part::Two, &100.into(), &Outcome::TooHigh);
+        submissions.record(day_5, Part::Two, &10.into(), &Outcome::TooLow);
+        submissions.record(day_5, Part::Two, &50.into(), &Outcome::Wrong);
+        submissions.record(
+            day_5,
+            Part::Two,
+            &60.into(),
+            &Outcome::Wait(Duration::from_secs(5)),
+        );
+        submissions.save().unwrap();
+
+        let mut submissions = Submissions::load(&path).unwrap();
+        assert_eq!(known(&submissions, 120), Some(Outcome::TooHigh));
+        assert_eq!(known(&submissions, 10), Some(Outcome::TooLow));
+        assert_eq!(known(&submissions, 50), Some(Outcome::Wrong));
+        assert_eq!(known(&submissions, 60), None);
+        assert_eq!(
+            submissions.known(day_5, Part::One, &Answer::Text("abc".to_string())),
+            None
+        );
+        submissions.record(day_5, Part::Two, &46.into(), &Outcome::Correct);
+        assert_eq!(known(&submissions, 46), Some(Outcome::Correct));
+        assert_eq!(known(&submissions, 47), Some(Outcome::Wrong));
+        // The same day of another year is kept apart.
+        let day_5_2022 = Puzzle::new(2022, 5).unwrap();
+        assert_eq!(submissions.known(day_5_2022, Part::Two, &47.into()), None);
+        submissions.record(day_5_2022, Part::Two, &47.into(), &Outcome::Correct);
+        submissions.save().unwrap();
+        let text = fs::read_to_string(&path).unwrap();
+        assert!(text.contains("[2022.day05.part_two]"), "{text}");
+        assert!(text.contains("[2023.day05.part_two]"), "{text}");
+        assert_eq!(known(&submissions, 47), Some(Outcome::Wrong));
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
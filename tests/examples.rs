@@ -0,0 +1,31 @@
+//! Runs every example under `examples/NN/` through the solvers of day `NN`, variants included.
+
+use aoc_2023::{
+    examples::{self, DEFAULT_EXAMPLES_DIR},
+    DAYS,
+};
+use std::{fs, path::Path};
+
+#[test]
+fn examples_give_the_expected_answers() {
+    let mut checked = 0;
+    let mut failures = Vec::new();
+    for dir in fs::read_dir(DEFAULT_EXAMPLES_DIR).unwrap() {
+        let dir = dir.unwrap().path();
+        let name = dir.file_name().unwrap().to_string_lossy().into_owned();
+        let Some(day) = DAYS.iter().find(|day| format!("{:02}", day.day) == name) else {
+            failures.push(format!("{}: no registered day", dir.display()));
+            continue;
+        };
+        for example in examples::load(Path::new(DEFAULT_EXAMPLES_DIR), day.day).unwrap() {
+            for answer in examples::check(day, &example) {
+                checked += 1;
+                if !answer.passed() && !answer.pending() {
+                    failures.push(format!("{}: {answer}", example.path.display()));
+                }
+            }
+        }
+    }
+    assert!(checked > 0, "no examples found in {DEFAULT_EXAMPLES_DIR}");
+    assert!(failures.is_empty(), "\n{}", failures.join("\n"));
+}
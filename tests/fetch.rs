@@ -0,0 +1,117 @@
+mod common;
+
+use aoc_2023::{AocError, Client, HttpCache, InputCache, InputSource, Puzzle};
+use common::StubServer;
+use std::{env, fs, path::PathBuf, time::Duration};
+
+const INPUT: &str = "0 3 6 9 12 15\n1 3 6 10 15 21\n10 13 16 21 30 45";
+
+fn client(server: &StubServer) -> Client {
+    Client::new()
+        .base_url(&server.base_url)
+        .min_interval(Duration::ZERO)
+        .backoff(Duration::from_millis(1))
+}
+
+fn day_9() -> Puzzle {
+    Puzzle::new(2023, 9).unwrap()
+}
+
+fn temp_dir(name: &str) -> PathBuf {
+    env::temp_dir().join(format!("aoc-2023-fetch-{name}-{}", std::process::id()))
+}
+
+#[test]
+fn sends_user_agent_and_cookie() {
+    let server = StubServer::start(|_, _| (200, format!("{INPUT}\n")));
+    let input = client(&server)
+        .user_agent("aoc-2023 tests")
+        .input(day_9(), "session=abc")
+        .unwrap();
+    assert_eq!(input, INPUT);
+    let requests = server.requests();
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].method, "GET");
+    assert_eq!(requests[0].path, "/2023/day/9/input");
+    assert_eq!(requests[0].headers["user-agent"], "aoc-2023 tests");
+    assert_eq!(requests[0].headers["cookie"], "session=abc");
+}
+
+#[test]
+fn retries_server_errors() {
+    let server = StubServer::start(|_, served| {
+        if served < 2 {
+            (503, "Service Unavailable".to_string())
+        } else {
+            (200, INPUT.to_string())
+        }
+    });
+    let input = client(&server).input(day_9(), "session=abc").unwrap();
+    assert_eq!(input, INPUT);
+    assert_eq!(server.requests().len(), 3);
+}
+
+#[test]
+fn gives_up_after_retries() {
+    let server = StubServer::start(|_, _| (500, "Internal Server Error".to_string()));
+    let result = client(&server).retries(2).input(day_9(), "session=abc");
+    assert!(matches!(
+        result,
+        Err(AocError::HttpStatus { status: 500, .. })
+    ));
+    assert_eq!(server.requests().len(), 3);
+}
+
+#[test]
+fn does_not_retry_client_errors() {
+    let server = StubServer::start(|_, _| {
+        (
+            400,
+            "Puzzle inputs differ by user.  Please log in to get your puzzle input.\n".to_string(),
+        )
+    });
+    let result = client(&server).input(day_9(), "session=expired");
+    assert!(matches!(
+        result,
+        Err(AocError::ErrorPage { cached: false, .. })
+    ));
+    assert_eq!(server.requests().len(), 1);
+}
+
+#[test]
+fn keeps_minimum_interval() {
+    let server = StubServer::start(|_, _| (200, INPUT.to_string()));
+    let client = client(&server).min_interval(Duration::from_millis(200));
+    let clone = client.clone();
+    client.input(day_9(), "session=abc").unwrap();
+    clone.input(day_9(), "session=abc").unwrap();
+    let requests = server.requests();
+    assert!(requests[1].received - requests[0].received >= Duration::from_millis(200));
+}
+
+#[test]
+fn http_cache_caches_input_but_not_error_pages() {
+    let server = StubServer::start(|request, _| {
+        if request.path == "/2023/day/9/input" {
+            (200, INPUT.to_string())
+        } else {
+            (404, "404 Not Found".to_string())
+        }
+    });
+    let dir = temp_dir("http-cache");
+    let source = HttpCache::new(InputCache::new(&dir))
+        .with_client(client(&server))
+        .with_cookie("session=abc");
+
+    assert_eq!(source.get(day_9()).unwrap().as_deref(), Some(INPUT));
+    assert_eq!(source.get(day_9()).unwrap().as_deref(), Some(INPUT));
+    assert_eq!(server.requests().len(), 1);
+
+    let day_10 = Puzzle::new(2023, 10).unwrap();
+    assert!(matches!(
+        source.get(day_10),
+        Err(AocError::ErrorPage { .. })
+    ));
+    assert_eq!(InputCache::new(&dir).get(day_10).unwrap(), None);
+    fs::remove_dir_all(dir).unwrap();
+}
@@ -0,0 +1,172 @@
+mod common;
+
+use aoc_2023::{
+    submit::{self, Outcome, Submission, Submissions},
+    Answer, AocError, Client, Part, Puzzle,
+};
+use common::StubServer;
+use std::{env, fs, time::Duration};
+
+fn client(server: &StubServer) -> Client {
+    Client::new()
+        .base_url(&server.base_url)
+        .min_interval(Duration::ZERO)
+}
+
+fn day_9() -> Puzzle {
+    Puzzle::new(2023, 9).unwrap()
+}
+
+fn page(message: &str) -> String {
+    format!("<html><body><main>\n<article><p>{message}</p></article>\n</main></body></html>")
+}
+
+fn submissions(name: &str) -> Submissions {
+    let dir = env::temp_dir().join(format!("aoc-2023-submit-{name}-{}", std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    Submissions::load(dir.join("submissions.toml")).unwrap()
+}
+
+const COOKIE: &str = "session=abc";
+
+#[test]
+fn posts_the_answer_and_records_the_outcome() {
+    let server = StubServer::start(|request, _| {
+        let message = if request.body.contains("answer=1000") {
+            "That's not the right answer; your answer is too high.  If you're stuck, make sure \
+             you're using the full input data; please wait one minute before trying again."
+        } else {
+            "That's the right answer!  You are one gold star closer to restoring snow operations."
+        };
+        (200, page(message))
+    });
+    let client = client(&server);
+    let mut submissions = submissions("records");
+    let mut submit = |answer: i32| {
+        submit::submit(
+            &client,
+            &mut submissions,
+            day_9(),
+            Part::Two,
+            &answer.into(),
+            || Ok(COOKIE.to_string()),
+        )
+        .unwrap()
+    };
+
+    let too_high = Submission {
+        outcome: Outcome::TooHigh,
+        sent: true,
+    };
+    assert_eq!(submit(1000), too_high);
+    let requests = server.requests();
+    assert_eq!(requests[0].method, "POST");
+    assert_eq!(requests[0].path, "/2023/day/9/answer");
+    assert_eq!(requests[0].headers["cookie"], COOKIE);
+    assert_eq!(requests[0].body, "level=2&answer=1000");
+
+    // Known to be too high, so neither sent again nor anything above it.
+    assert_eq!(
+        submit(1200),
+        Submission {
+            sent: false,
+            ..too_high
+        }
+    );
+    assert_eq!(server.requests().len(), 1);
+
+    assert_eq!(submit(925).outcome, Outcome::Correct);
+    assert_eq!(server.requests().len(), 2);
+    submissions.save().unwrap();
+
+    let mut submissions = Submissions::load(submissions.path()).unwrap();
+    let known = submit::submit(
+        &client,
+        &mut submissions,
+        day_9(),
+        Part::Two,
+        &925.into(),
+        || -> Result<String, AocError> { panic!("the cookie is not needed") },
+    )
+    .unwrap();
+    assert_eq!(
+        known,
+        Submission {
+            outcome: Outcome::Correct,
+            sent: false,
+        }
+    );
+    fs::remove_dir_all(submissions.path().parent().unwrap()).unwrap();
+}
+
+#[test]
+fn waits_are_not_recorded() {
+    let server = StubServer::start(|_, _| {
+        (
+            200,
+            page(
+                "You gave an answer too recently; you have to wait after submitting an answer \
+                 before trying again.  You have 37s left to wait.",
+            ),
+        )
+    });
+    let client = client(&server);
+    let mut submissions = submissions("waits");
+    let answer = Answer::from(68);
+    let submission = submit::submit(
+        &client,
+        &mut submissions,
+        day_9(),
+        Part::One,
+        &answer,
+        || Ok(COOKIE.to_string()),
+    )
+    .unwrap();
+    assert_eq!(submission.outcome, Outcome::Wait(Duration::from_secs(37)));
+    assert_eq!(submissions.known(day_9(), Part::One, &answer), None);
+    fs::remove_dir_all(submissions.path().parent().unwrap()).unwrap();
+}
+
+#[test]
+fn rejects_unrecognized_pages_and_errors() {
+    let server = StubServer::start(|_, served| match served {
+        0 => (200, page("To play, please identify yourself.")),
+        _ => (500, "Internal Server Error".to_string()),
+    });
+    let client = client(&server);
+    let mut submissions = submissions("rejects");
+    let mut submit = || {
+        submit::submit(
+            &client,
+            &mut submissions,
+            day_9(),
+            Part::One,
+            &68.into(),
+            || Ok(COOKIE.to_string()),
+        )
+    };
+    assert!(matches!(
+        submit(),
+        Err(AocError::UnrecognizedResponse { text, .. }) if text == "To play, please identify yourself."
+    ));
+    assert!(matches!(
+        submit(),
+        Err(AocError::HttpStatus { status: 500, .. })
+    ));
+    // Server errors are not retried, so that an answer is never sent twice.
+    assert_eq!(server.requests().len(), 2);
+    assert!(matches!(
+        submit::submit(
+            &client,
+            &mut submissions,
+            day_9(),
+            Part::One,
+            &68.into(),
+            || Err(AocError::MissingCookie),
+        ),
+        Err(AocError::MissingCookie)
+    ));
+    assert_eq!(server.requests().len(), 2);
+    fs::remove_dir_all(submissions.path().parent().unwrap()).unwrap();
+}